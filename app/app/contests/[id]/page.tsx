@@ -7,7 +7,7 @@ import { useConnection, useWallet } from '@solana/wallet-adapter-react';
 import { AnchorProvider, Wallet, BN } from '@coral-xyz/anchor';
 import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
 import { WalletButton } from '../../components/WalletButton';
-import { getProgram, getEscrowPDA, getSubmissionPDA, getVotePDA, getGasPoolPDA } from '../../lib/program';
+import { getProgram, getEscrowPDA, getSubmissionPDA, getRegistrationPDA, getVotePDA, getGasPoolPDA, getEntryFeeAccounts, getGateAccounts } from '../../lib/program';
 
 export default function ContestDetailPage({ params }: { params: Promise<{ id: string }> }) {
   const resolvedParams = use(params);
@@ -22,6 +22,7 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
 
   // Form states
   const [submissionUrl, setSubmissionUrl] = useState('');
+  const [selectedTrack, setSelectedTrack] = useState(0);
   const [selectedWinner, setSelectedWinner] = useState('');
   const [fundingAmount, setFundingAmount] = useState('');
   const [gasBudget, setGasBudget] = useState('');
@@ -53,7 +54,7 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
       const allSubmissions = await (program.account as any).submission.all([
         {
           memcmp: {
-            offset: 8 + 32,
+            offset: 8 + 1 + 32, // discriminator, version, judge/participant
             bytes: contestPubkey.toBase58(),
           },
         },
@@ -64,7 +65,7 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
       const allVotes = await (program.account as any).judgeVoteAccount.all([
         {
           memcmp: {
-            offset: 8 + 32,
+            offset: 8 + 1 + 32, // discriminator, version, judge/participant
             bytes: contestPubkey.toBase58(),
           },
         },
@@ -102,7 +103,7 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
         .accounts({
           contest: contest.publicKey,
           escrow: escrowPDA,
-          creator: wallet.publicKey,
+          authority: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -133,13 +134,23 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
       const program = getProgram(provider);
 
       const [submissionPDA] = getSubmissionPDA(contest.publicKey, wallet.publicKey);
+      // Registration-gated contests need the participant's registration PDA
+      const [registrationPDA] = getRegistrationPDA(contest.publicKey, wallet.publicKey);
+      const registrationEnabled = !contest.account.registrationDeadline.isZero();
 
       const tx = await program.methods
-        .submitEntry(submissionUrl)
+        // Open entry: no allowlist proof and no sealed commitment
+        .submitEntry(submissionUrl, [], null, selectedTrack)
         .accounts({
           contest: contest.publicKey,
           submission: submissionPDA,
+          registration: registrationEnabled ? registrationPDA : null,
           participant: wallet.publicKey,
+          payer: wallet.publicKey,
+          // Sponsored contests pay the submission rent from here
+          gasPool: getGasPoolPDA(contest.publicKey)[0],
+          ...getEntryFeeAccounts(contest.account, wallet.publicKey),
+          ...getGateAccounts(contest.account, wallet.publicKey),
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -170,11 +181,14 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
       );
       const program = getProgram(provider);
 
-      const [votePDA] = getVotePDA(contest.publicKey, wallet.publicKey);
       const winnerPubkey = new PublicKey(selectedWinner);
+      // Votes are cast in the track the chosen entry was submitted to
+      const winnerSubmission = submissions.find((s) => s.account.participant.equals(winnerPubkey));
+      const track: number = winnerSubmission?.account.track ?? 0;
+      const [votePDA] = getVotePDA(contest.publicKey, wallet.publicKey, track);
 
       const tx = await program.methods
-        .judgeVote(winnerPubkey)
+        .judgeVote(winnerPubkey, track)
         .accounts({
           contest: contest.publicKey,
           vote: votePDA,
@@ -213,36 +227,42 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
         BigInt(contest.account.contestId.toString())
       );
 
-      // Find the winner based on votes
+      // Find a winner based on votes, per track. Tracks already paid out are skipped
       const voteCounts = new Map<string, number>();
       votes.forEach((vote) => {
-        const winner = vote.account.winner.toString();
-        voteCounts.set(winner, (voteCounts.get(winner) || 0) + 1);
+        const key = `${vote.account.track}:${vote.account.winner.toString()}`;
+        voteCounts.set(key, (voteCounts.get(key) || 0) + 1);
       });
 
-      const winnerEntry = Array.from(voteCounts.entries()).find(
-        ([_, count]) => count >= contest.account.approvalThreshold
-      );
+      const winnerEntry = Array.from(voteCounts.entries()).find(([key, count]) => {
+        const track = Number(key.split(':')[0]);
+        return count >= contest.account.approvalThreshold && !contest.account.tracks[track]?.winner;
+      });
 
       if (!winnerEntry) {
         throw new Error('No consensus reached yet');
       }
 
-      const winnerPubkey = new PublicKey(winnerEntry[0]);
+      const [trackKey, winnerKey] = winnerEntry[0].split(':');
+      const track = Number(trackKey);
+      const winnerPubkey = new PublicKey(winnerKey);
 
-      // Get all vote PDAs for remaining accounts
-      const voteAccounts = votes.map((v) => ({
-        pubkey: v.publicKey,
-        isSigner: false,
-        isWritable: false,
-      }));
+      // Get the track's vote PDAs for remaining accounts
+      const voteAccounts = votes
+        .filter((v) => v.account.track === track)
+        .map((v) => ({
+          pubkey: v.publicKey,
+          isSigner: false,
+          isWritable: false,
+        }));
 
       const tx = await program.methods
-        .distributePrizes()
+        .distributePrizes(track)
         .accounts({
           contest: contest.publicKey,
           escrow: escrowPDA,
           winner: winnerPubkey,
+          winnerSubmission: getSubmissionPDA(contest.publicKey, winnerPubkey)[0],
           systemProgram: SystemProgram.programId,
         })
         .remainingAccounts(voteAccounts)
@@ -283,7 +303,8 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
         .accounts({
           contest: contest.publicKey,
           gasPool: gasPoolPDA,
-          creator: wallet.publicKey,
+          operator: wallet.publicKey,
+          role: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -322,7 +343,7 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
     );
   }
 
-  const isCreator = wallet.publicKey?.equals(contest.account.creator);
+  const isAuthority = wallet.publicKey?.equals(contest.account.authority);
   const isJudge = wallet.publicKey && contest.account.judges.some((j: PublicKey) => j.equals(wallet.publicKey!));
   const deadline = new Date(contest.account.submissionDeadline.toNumber() * 1000);
   const isBeforeDeadline = new Date() < deadline;
@@ -402,7 +423,7 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
           </div>
 
           {/* Fund Contest (Creator only, if not funded) */}
-          {isCreator && !contest.account.funded && statusKey === 'setup' && (
+          {isAuthority && !contest.account.funded && statusKey === 'setup' && (
             <div className="mt-6 card-neo bg-[#ffbe0b]">
               <p className="text-[#001858] font-black mb-3 uppercase">⚠️ Contest needs to be funded to activate</p>
               <button
@@ -428,7 +449,7 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
           )}
 
           {/* Enable Gas Sponsorship (Creator only, when active and not yet enabled) */}
-          {isCreator && statusKey === 'active' && !contest.account.gasSponsorshipEnabled && (
+          {isAuthority && statusKey === 'active' && !contest.account.gasSponsorshipEnabled && (
             <div className="mt-6 card-neo bg-[#8bd3dd]">
               <h3 className="text-xl font-black text-[#001858] mb-3 uppercase">⛽ Enable Gas Sponsorship</h3>
               <p className="text-[#001858] font-bold text-sm mb-4">
@@ -478,6 +499,24 @@ export default function ContestDetailPage({ params }: { params: Promise<{ id: st
                     required
                   />
                 </div>
+                {contest.account.tracks.length > 0 && (
+                  <div>
+                    <label className="block text-[#001858] font-black mb-2 uppercase text-sm">
+                      Track
+                    </label>
+                    <select
+                      value={selectedTrack}
+                      onChange={(e) => setSelectedTrack(parseInt(e.target.value))}
+                      className="input-neo w-full bg-white text-[#001858] font-bold"
+                    >
+                      {contest.account.tracks.map((track: any, index: number) => (
+                        <option key={index} value={index}>
+                          {track.name}
+                        </option>
+                      ))}
+                    </select>
+                  </div>
+                )}
                 <button
                   type="submit"
                   disabled={actionLoading || !wallet.publicKey}
@@ -8,7 +8,14 @@ import { SystemProgram } from '@solana/web3.js';
 import BN from 'bn.js';
 import Link from 'next/link';
 import { WalletButton } from '../../components/WalletButton';
-import { getProgram, getContestPDA } from '../../lib/program';
+import {
+  getProgram,
+  getContestPDA,
+  getRegistryPDA,
+  getCreatorIndexPDA,
+  getCreatorIndexPagePDA,
+  CREATOR_INDEX_PAGE_SIZE,
+} from '../../lib/program';
 
 export default function CreateContest() {
   const router = useRouter();
@@ -61,9 +68,12 @@ export default function CreateContest() {
         throw new Error('At least one judge is required');
       }
 
-      if (formData.approvalThreshold > validJudges.length) {
+      if (
+        !(formData.approvalThreshold >= 1) ||
+        formData.approvalThreshold > validJudges.length
+      ) {
         throw new Error(
-          'Approval threshold cannot exceed number of judges'
+          'Approval threshold must be between 1 and the number of judges'
         );
       }
 
@@ -75,9 +85,20 @@ export default function CreateContest() {
       );
       const program = getProgram(provider);
 
-      // Generate contest ID (timestamp + random)
-      const contestId = new BN(Date.now());
-      const contestIdBigInt = BigInt(contestId.toString());
+      // The registry hands out the next contest ID
+      const [registryPDA] = getRegistryPDA();
+      const registry = await (program.account as any).registry.fetch(registryPDA);
+      const contestIdBigInt = BigInt(registry.contestCount.toString());
+
+      // New contests are recorded on the creator's current index page
+      const [creatorIndexPDA] = getCreatorIndexPDA(wallet.publicKey);
+      const creatorIndex = await (program.account as any).creatorIndex.fetchNullable(
+        creatorIndexPDA
+      );
+      const [indexPagePDA] = getCreatorIndexPagePDA(
+        wallet.publicKey,
+        Math.floor((creatorIndex?.contestCount ?? 0) / CREATOR_INDEX_PAGE_SIZE)
+      );
 
       // Convert prize amount to lamports
       const prizeInLamports = new BN(
@@ -100,16 +121,19 @@ export default function CreateContest() {
       // Create contest
       const tx = await program.methods
         .createContest(
-          contestId,
           formData.title,
           formData.description,
           prizeInLamports,
           deadlineTimestamp,
           judgePublicKeys,
-          formData.approvalThreshold
+          formData.approvalThreshold,
+          null // open entry; set_allowlist_root can restrict it later
         )
         .accounts({
+          registry: registryPDA,
           contest: contestPDA,
+          creatorIndex: creatorIndexPDA,
+          indexPage: indexPagePDA,
           creator: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -1,4 +1,4 @@
-import { AnchorProvider, Program, Idl } from '@coral-xyz/anchor';
+import { AnchorProvider, Program, Idl, utils } from '@coral-xyz/anchor';
 import { Connection, PublicKey } from '@solana/web3.js';
 import idl from './solarena.json';
 
@@ -15,6 +15,31 @@ export function getProgramPDA(
   return PublicKey.findProgramAddressSync(seeds, programId);
 }
 
+// Contest IDs per creator index page (matches CREATOR_INDEX_PAGE_SIZE on-chain)
+export const CREATOR_INDEX_PAGE_SIZE = 32;
+
+// Helper to get the global registry PDA that hands out contest IDs
+export function getRegistryPDA() {
+  return getProgramPDA([Buffer.from('registry')]);
+}
+
+// Helper to get a creator's index header PDA
+export function getCreatorIndexPDA(creator: PublicKey) {
+  return getProgramPDA([Buffer.from('creator_index'), creator.toBuffer()]);
+}
+
+// Helper to get one page of a creator's contest index
+export function getCreatorIndexPagePDA(creator: PublicKey, page: number) {
+  const buffer = Buffer.alloc(4);
+  buffer.writeUInt32LE(page);
+
+  return getProgramPDA([
+    Buffer.from('creator_index'),
+    creator.toBuffer(),
+    buffer,
+  ]);
+}
+
 // Helper to get contest PDA
 export function getContestPDA(creator: PublicKey, contestId: bigint) {
   // Convert bigint to 8-byte buffer (little-endian)
@@ -52,12 +77,22 @@ export function getSubmissionPDA(contest: PublicKey, participant: PublicKey) {
   ]);
 }
 
-// Helper to get vote PDA
-export function getVotePDA(contest: PublicKey, judge: PublicKey) {
+// Helper to get registration PDA
+export function getRegistrationPDA(contest: PublicKey, participant: PublicKey) {
+  return getProgramPDA([
+    Buffer.from('registration'),
+    contest.toBuffer(),
+    participant.toBuffer(),
+  ]);
+}
+
+// Helper to get vote PDA (one per judge per track)
+export function getVotePDA(contest: PublicKey, judge: PublicKey, track: number) {
   return getProgramPDA([
     Buffer.from('vote'),
     contest.toBuffer(),
     judge.toBuffer(),
+    Buffer.from([track]),
   ]);
 }
 
@@ -68,3 +103,40 @@ export function getGasPoolPDA(contest: PublicKey) {
     contest.toBuffer(),
   ]);
 }
+
+// Accounts submit_entry needs to collect a contest's entry fee. Free contests
+// pass none; lamport fees only need the escrow; token fees also need the
+// participant's and the escrow's associated token accounts.
+export function getEntryFeeAccounts(contest: any, participant: PublicKey) {
+  const none = {
+    escrow: null,
+    feeMint: null,
+    participantFeeAccount: null,
+    escrowFeeAccount: null,
+    tokenProgram: null,
+  };
+  if (contest.entryFee.isZero()) return none;
+
+  const [escrow] = getEscrowPDA(contest.creator, BigInt(contest.contestId.toString()));
+  const mint: PublicKey | null = contest.entryFeeMint;
+  if (!mint) return { ...none, escrow };
+
+  return {
+    escrow,
+    feeMint: mint,
+    participantFeeAccount: utils.token.associatedAddress({ mint, owner: participant }),
+    escrowFeeAccount: utils.token.associatedAddress({ mint, owner: escrow }),
+    tokenProgram: utils.token.TOKEN_PROGRAM_ID,
+  };
+}
+
+// Holding accounts submit_entry checks for gated contests. Token gates read
+// the participant's associated token account for the gate mint; NFT gates
+// need the metadata of a specific NFT, which the participant must choose.
+export function getGateAccounts(contest: any, participant: PublicKey) {
+  const mint: PublicKey | undefined = contest.participationGate?.tokenBalance?.mint;
+  return {
+    gateTokenAccount: mint ? utils.token.associatedAddress({ mint, owner: participant }) : null,
+    gateNftMetadata: null,
+  };
+}
@@ -0,0 +1,131 @@
+//! Off-chain contest metadata for SolArena
+//!
+//! A contest may point to a JSON document through `Contest.metadata_uri`
+//! and commit to its exact bytes with `Contest.metadata_hash`
+//! (`sha256(document_bytes)`). The document carries what does not fit
+//! on-chain: full rules, judging criteria and assets.
+//!
+//! Clients fetch the document from the URI, then call [`verify`] with the
+//! on-chain hash before trusting its contents.
+//!
+//! # Example document
+//! ```json
+//! {
+//!   "version": 1,
+//!   "title": "Solana Summer Hackathon",
+//!   "summary": "Build anything on Solana in two weeks.",
+//!   "rules": "One entry per team. Code must be open source.",
+//!   "judging_criteria": [
+//!     { "name": "Innovation", "description": "Novelty of the idea", "weight": 40 },
+//!     { "name": "Execution", "description": "Quality of the build", "weight": 60 }
+//!   ],
+//!   "assets": [
+//!     { "name": "Banner", "uri": "https://example.com/banner.png", "mime_type": "image/png" }
+//!   ],
+//!   "links": [
+//!     { "label": "Discord", "url": "https://discord.gg/example" }
+//!   ]
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Current schema version
+pub const METADATA_VERSION: u16 = 1;
+
+/// Maximum length of `Contest.metadata_uri` (mirrors the on-chain limit)
+pub const MAX_METADATA_URI_LEN: usize = 200;
+
+/// Contest metadata document referenced by `Contest.metadata_uri`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContestMetadata {
+    /// Schema version; documents with a newer version are rejected
+    pub version: u16,
+    pub title: String,
+    pub summary: String,
+    /// Full contest rules (Markdown)
+    pub rules: String,
+    #[serde(default)]
+    pub judging_criteria: Vec<JudgingCriterion>,
+    #[serde(default)]
+    pub assets: Vec<Asset>,
+    #[serde(default)]
+    pub links: Vec<Link>,
+}
+
+/// One criterion judges score entries on
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JudgingCriterion {
+    pub name: String,
+    pub description: String,
+    /// Relative weight; weights of all criteria should sum to 100
+    pub weight: u8,
+}
+
+/// A file attached to the contest (banner, starter kit, dataset, ...)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Asset {
+    pub name: String,
+    pub uri: String,
+    pub mime_type: String,
+}
+
+/// An external link (community, docs, FAQ, ...)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Link {
+    pub label: String,
+    pub url: String,
+}
+
+/// Reasons a fetched document cannot be trusted
+#[derive(Debug, thiserror::Error)]
+pub enum MetadataError {
+    #[error("metadata hash does not match the on-chain commitment")]
+    HashMismatch,
+
+    #[error("unsupported metadata version {0}")]
+    UnsupportedVersion(u16),
+
+    #[error("judging criteria weights sum to {0}, expected 100")]
+    InvalidWeights(u32),
+
+    #[error("invalid metadata JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Hash to store in `Contest.metadata_hash` for a document's exact bytes
+pub fn metadata_hash(document: &[u8]) -> [u8; 32] {
+    Sha256::digest(document).into()
+}
+
+/// Checks a fetched document against the on-chain hash and parses it
+pub fn verify(document: &[u8], expected_hash: &[u8; 32]) -> Result<ContestMetadata, MetadataError> {
+    if metadata_hash(document) != *expected_hash {
+        return Err(MetadataError::HashMismatch);
+    }
+    let metadata: ContestMetadata = serde_json::from_slice(document)?;
+    metadata.validate()?;
+    Ok(metadata)
+}
+
+impl ContestMetadata {
+    /// Checks schema-level rules that serde cannot express
+    pub fn validate(&self) -> Result<(), MetadataError> {
+        if self.version > METADATA_VERSION {
+            return Err(MetadataError::UnsupportedVersion(self.version));
+        }
+        if !self.judging_criteria.is_empty() {
+            let total: u32 = self.judging_criteria.iter().map(|c| c.weight as u32).sum();
+            if total != 100 {
+                return Err(MetadataError::InvalidWeights(total));
+            }
+        }
+        Ok(())
+    }
+
+    /// Serializes the document; hash these exact bytes with [`metadata_hash`]
+    pub fn to_json(&self) -> Result<Vec<u8>, MetadataError> {
+        Ok(serde_json::to_vec_pretty(self)?)
+    }
+}
@@ -0,0 +1,59 @@
+//! Relayer settings, read from environment variables
+
+use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::error::RelayError;
+
+/// Runtime settings
+///
+/// | Variable | Default |
+/// |----------|---------|
+/// | `SOLARENA_RPC_URL` | `http://127.0.0.1:8899` |
+/// | `SOLARENA_RELAYER_KEYPAIR` | `~/.config/solana/id.json` |
+/// | `SOLARENA_RELAYER_LISTEN` | `127.0.0.1:8080` |
+/// | `SOLARENA_RELAYER_WALLET_LIMIT` | `10` transactions per wallet per window |
+/// | `SOLARENA_RELAYER_WALLET_WINDOW_SECS` | `86400` |
+pub struct RelayerConfig {
+    pub rpc_url: String,
+    pub keypair_path: PathBuf,
+    pub listen_addr: String,
+    pub wallet_limit: u32,
+    pub wallet_window: Duration,
+}
+
+impl RelayerConfig {
+    pub fn from_env() -> Result<Self, RelayError> {
+        let keypair_path = match env::var("SOLARENA_RELAYER_KEYPAIR") {
+            Ok(path) => PathBuf::from(path),
+            Err(_) => {
+                let home = env::var("HOME").map_err(|_| {
+                    RelayError::Config("set SOLARENA_RELAYER_KEYPAIR or HOME".to_string())
+                })?;
+                PathBuf::from(home).join(".config/solana/id.json")
+            }
+        };
+
+        Ok(Self {
+            rpc_url: var_or("SOLARENA_RPC_URL", "http://127.0.0.1:8899"),
+            keypair_path,
+            listen_addr: var_or("SOLARENA_RELAYER_LISTEN", "127.0.0.1:8080"),
+            wallet_limit: parse_var("SOLARENA_RELAYER_WALLET_LIMIT", 10)?,
+            wallet_window: Duration::from_secs(parse_var("SOLARENA_RELAYER_WALLET_WINDOW_SECS", 86_400)?),
+        })
+    }
+}
+
+fn var_or(name: &str, default: &str) -> String {
+    env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+fn parse_var<T: std::str::FromStr>(name: &str, default: T) -> Result<T, RelayError> {
+    match env::var(name) {
+        Ok(value) => value
+            .parse()
+            .map_err(|_| RelayError::Config(format!("{name} is not a valid number"))),
+        Err(_) => Ok(default),
+    }
+}
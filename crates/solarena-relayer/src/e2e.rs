@@ -0,0 +1,311 @@
+//! End-to-end relay against a local validator
+//!
+//! Needs `solana-test-validator` on the PATH and the program built with
+//! `anchor build`, so it is ignored by default:
+//!
+//! ```bash
+//! cargo test -p solarena-relayer -- --ignored
+//! ```
+
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use solarena::state::ConfigParams;
+use tiny_http::Server;
+
+use crate::policy::Policy;
+use crate::rpc::{Ledger, RpcClient};
+use crate::Relayer;
+
+const RPC_PORT: u16 = 18_899;
+const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Stops the validator when the test ends, pass or fail
+struct Validator(Child);
+
+impl Drop for Validator {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn rpc_url() -> String {
+    format!("http://127.0.0.1:{RPC_PORT}")
+}
+
+fn call(method: &str, params: Value) -> Value {
+    let response: Value = ureq::post(&rpc_url())
+        .send_json(json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+        .and_then(|response| Ok(response.into_json()?))
+        .unwrap_or_else(|e| panic!("{method}: {e}"));
+    assert!(response.get("error").is_none(), "{method}: {response}");
+    response["result"].clone()
+}
+
+fn wait_until(what: &str, mut done: impl FnMut() -> bool) {
+    let start = Instant::now();
+    while !done() {
+        assert!(start.elapsed() < Duration::from_secs(60), "timed out waiting for {what}");
+        thread::sleep(Duration::from_millis(250));
+    }
+}
+
+fn start_validator(upgrade_authority: &Pubkey) -> Validator {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let program = root.join("target/deploy/solarena.so");
+    assert!(program.exists(), "run `anchor build` first");
+
+    let child = Command::new("solana-test-validator")
+        .args(["--reset", "--quiet", "--rpc-port", &RPC_PORT.to_string(), "--ledger"])
+        .arg(std::env::temp_dir().join("solarena-relayer-e2e"))
+        .arg("--upgradeable-program")
+        .arg(solarena::ID.to_string())
+        .arg(&program)
+        .arg(upgrade_authority.to_string())
+        .stdout(Stdio::null())
+        .spawn()
+        .expect("solana-test-validator must be on the PATH");
+    let validator = Validator(child);
+
+    wait_until("the validator", || {
+        ureq::post(&rpc_url())
+            .send_json(json!({ "jsonrpc": "2.0", "id": 1, "method": "getHealth" }))
+            .is_ok()
+    });
+    validator
+}
+
+fn airdrop(to: &Pubkey, lamports: u64) {
+    let signature = call("requestAirdrop", json!([to.to_string(), lamports]));
+    confirm(signature.as_str().unwrap());
+}
+
+fn confirm(signature: &str) {
+    wait_until("confirmation", || {
+        let status = &call("getSignatureStatuses", json!([[signature]]))["value"][0];
+        assert!(status["err"].is_null(), "{signature} failed: {status}");
+        matches!(status["confirmationStatus"].as_str(), Some("confirmed" | "finalized"))
+    });
+}
+
+fn latest_blockhash() -> String {
+    let result = call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]));
+    result["value"]["blockhash"].as_str().unwrap().to_string()
+}
+
+fn send(rpc: &RpcClient, instruction: Instruction, signers: &[&Keypair]) {
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&signers[0].pubkey()));
+    transaction.sign(signers, latest_blockhash().parse().unwrap());
+    confirm(&rpc.send_transaction(&transaction).unwrap());
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &solarena::ID).0
+}
+
+fn instruction(accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+    Instruction::new_with_bytes(solarena::ID, &args.data(), accounts.to_account_metas(None))
+}
+
+#[test]
+#[ignore = "needs solana-test-validator and target/deploy/solarena.so"]
+fn relays_a_sponsored_submit_entry() {
+    let admin = Keypair::new();
+    let relayer = Keypair::new();
+    let participant = Keypair::new();
+    let _validator = start_validator(&admin.pubkey());
+    let rpc = RpcClient::new(rpc_url());
+
+    airdrop(&admin.pubkey(), 10 * LAMPORTS_PER_SOL);
+    airdrop(&relayer.pubkey(), LAMPORTS_PER_SOL);
+
+    // Protocol config naming the relayer, then a funded, sponsored contest
+    let config = pda(&[b"config"]);
+    let registry = pda(&[b"registry"]);
+    send(
+        &rpc,
+        instruction(
+            solarena::accounts::InitializeConfig {
+                config,
+                treasury: pda(&[b"treasury"]),
+                program: solarena::ID,
+                program_data: Pubkey::find_program_address(
+                    &[solarena::ID.as_ref()],
+                    &bpf_loader_upgradeable::ID,
+                )
+                .0,
+                authority: admin.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            solarena::instruction::InitializeConfig {
+                admin: admin.pubkey(),
+                params: ConfigParams {
+                    min_prize_amount: LAMPORTS_PER_SOL / 100,
+                    max_judges: 5,
+                    reclaim_delay: 3_600,
+                    max_url_len: 200,
+                    platform_fee_bps: 0,
+                    relayer: Some(relayer.pubkey()),
+                },
+            },
+        ),
+        &[&admin],
+    );
+    send(
+        &rpc,
+        instruction(
+            solarena::accounts::InitializeRegistry {
+                registry,
+                payer: admin.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            solarena::instruction::InitializeRegistry {},
+        ),
+        &[&admin],
+    );
+
+    let creator = admin.pubkey();
+    let contest = pda(&[b"contest", creator.as_ref(), &0u64.to_le_bytes()]);
+    let deadline = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+        + 3_600;
+    send(
+        &rpc,
+        instruction(
+            solarena::accounts::CreateContest {
+                config,
+                registry,
+                contest,
+                creator_index: pda(&[b"creator_index", creator.as_ref()]),
+                index_page: pda(&[b"creator_index", creator.as_ref(), &0u32.to_le_bytes()]),
+                creator,
+                system_program: anchor_lang::system_program::ID,
+            },
+            solarena::instruction::CreateContest {
+                title: "Relayed".to_string(),
+                description: "End-to-end relayer test".to_string(),
+                prize_amount: LAMPORTS_PER_SOL / 10,
+                submission_deadline: deadline,
+                judges: vec![creator],
+                approval_threshold: 1,
+                allowlist_root: None,
+            },
+        ),
+        &[&admin],
+    );
+    send(
+        &rpc,
+        instruction(
+            solarena::accounts::FundContest {
+                contest,
+                escrow: pda(&[b"escrow", creator.as_ref(), &0u64.to_le_bytes()]),
+                authority: creator,
+                system_program: anchor_lang::system_program::ID,
+            },
+            solarena::instruction::FundContest {},
+        ),
+        &[&admin],
+    );
+    let gas_pool = pda(&[b"gas_pool", contest.as_ref()]);
+    send(
+        &rpc,
+        instruction(
+            solarena::accounts::EnableGasSponsorship {
+                contest,
+                gas_pool,
+                operator: creator,
+                role: None,
+                system_program: anchor_lang::system_program::ID,
+            },
+            solarena::instruction::EnableGasSponsorship {
+                gas_budget: LAMPORTS_PER_SOL / 10,
+            },
+        ),
+        &[&admin],
+    );
+
+    // The relayer serves HTTP on a background thread
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let relay_url = format!("http://{}", server.server_addr());
+    let relayer_key = relayer.pubkey();
+    thread::spawn(move || {
+        let mut relayer = Relayer {
+            policy: Policy::new(relayer.pubkey(), 5, Duration::from_secs(3_600)),
+            rpc: RpcClient::new(rpc_url()),
+            keypair: relayer,
+        };
+        for request in server.incoming_requests() {
+            relayer.handle(request);
+        }
+    });
+
+    let payer: Value = ureq::get(&format!("{relay_url}/payer"))
+        .call()
+        .unwrap()
+        .into_json()
+        .unwrap();
+    assert_eq!(payer["payer"], relayer_key.to_string());
+    let relayer_balance = rpc.get_balance(&relayer_key).unwrap();
+
+    // A participant with no SOL signs; the relayer pays and is reimbursed
+    let submission = pda(&[b"submission", contest.as_ref(), participant.pubkey().as_ref()]);
+    let submit = instruction(
+        solarena::accounts::SubmitEntry {
+            config,
+            contest,
+            submission,
+            registration: None,
+            participant: participant.pubkey(),
+            payer: relayer_key,
+            gas_pool,
+            escrow: None,
+            fee_mint: None,
+            participant_fee_account: None,
+            escrow_fee_account: None,
+            token_program: None,
+            gate_token_account: None,
+            gate_nft_metadata: None,
+            system_program: anchor_lang::system_program::ID,
+            instructions: anchor_lang::solana_program::sysvar::instructions::ID,
+        },
+        solarena::instruction::SubmitEntry {
+            submission_url: "https://example.com/relayed".to_string(),
+            allowlist_proof: Vec::new(),
+            commitment: None,
+            track: 0,
+        },
+    );
+    let mut transaction = Transaction::new_with_payer(&[submit], Some(&relayer_key));
+    transaction.partial_sign(&[&participant], latest_blockhash().parse().unwrap());
+    let body = json!({
+        "transaction": BASE64.encode(bincode::serialize(&transaction).unwrap()),
+    });
+    let response: Value = ureq::post(&format!("{relay_url}/relay"))
+        .send_json(body)
+        .unwrap()
+        .into_json()
+        .unwrap();
+    confirm(response["signature"].as_str().expect("relay returned a signature"));
+
+    let data = rpc.get_account_data(&submission).unwrap().expect("submission exists");
+    let recorded: solarena::state::Submission =
+        anchor_lang::AccountDeserialize::try_deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!(recorded.participant, participant.pubkey());
+    assert_eq!(rpc.get_balance(&participant.pubkey()).unwrap(), 0);
+    assert_eq!(rpc.get_balance(&relayer_key).unwrap(), relayer_balance);
+}
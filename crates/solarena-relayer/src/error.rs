@@ -0,0 +1,66 @@
+//! Relayer errors and their HTTP status codes
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RelayError {
+    #[error("invalid configuration: {0}")]
+    Config(String),
+
+    #[error("transaction could not be decoded: {0}")]
+    Decode(String),
+
+    #[error("fee payer must be the relayer")]
+    FeePayerMismatch,
+
+    #[error("expected exactly one instruction")]
+    InstructionCount,
+
+    #[error("expected exactly two signers: the relayer and the participant")]
+    SignerCount,
+
+    #[error("only SolArena instructions are relayed")]
+    ForeignProgram,
+
+    #[error("only submit_entry and update_submission are relayed")]
+    UnsupportedInstruction,
+
+    #[error("relayer may only appear as fee payer and instruction payer")]
+    RelayerMisused,
+
+    #[error("contest does not have gas sponsorship enabled")]
+    SponsorshipDisabled,
+
+    #[error("contest is paused")]
+    ContestPaused,
+
+    #[error("update changes nothing, so it is not sponsored")]
+    NoChange,
+
+    #[error("gas pool cannot cover this transaction")]
+    GasPoolTooLow,
+
+    #[error("wallet has reached its relay limit")]
+    WalletLimit,
+
+    #[error("participant signature is missing or invalid")]
+    InvalidSignature,
+
+    #[error("account not found: {0}")]
+    AccountNotFound(String),
+
+    #[error("RPC error: {0}")]
+    Rpc(String),
+}
+
+impl RelayError {
+    /// HTTP status returned to the client
+    pub fn status(&self) -> u16 {
+        match self {
+            RelayError::Config(_) | RelayError::Rpc(_) => 502,
+            RelayError::Decode(_) | RelayError::AccountNotFound(_) => 400,
+            RelayError::WalletLimit => 429,
+            _ => 403,
+        }
+    }
+}
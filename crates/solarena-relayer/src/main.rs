@@ -0,0 +1,151 @@
+//! Fee-payer relayer for gas-free SolArena participation
+//!
+//! Participants build a `submit_entry` or `update_submission` transaction with
+//! the relayer as fee payer and as the instruction's `payer`, sign it, and POST
+//! it here. The relayer checks it against [`policy`], co-signs and submits it.
+//! On-chain, the contest's gas pool reimburses the relayer for the fee and rent.
+//!
+//! # Endpoints
+//! - `GET /payer` - `{"payer": "<relayer pubkey>"}`
+//! - `POST /relay` - body `{"transaction": "<base64 bincode Transaction>"}`,
+//!   returns `{"signature": "..."}` or `{"error": "..."}`
+//!
+//! Settings come from environment variables (see [`config::RelayerConfig`]).
+
+mod config;
+mod error;
+mod policy;
+mod rpc;
+
+#[cfg(test)]
+mod e2e;
+
+use std::io::Read;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use config::RelayerConfig;
+use error::RelayError;
+use policy::Policy;
+use rpc::RpcClient;
+
+/// Largest request body accepted (a legacy transaction is at most 1232 bytes)
+const MAX_BODY_LEN: u64 = 4096;
+
+#[derive(Deserialize)]
+struct RelayRequest {
+    transaction: String,
+}
+
+struct Relayer {
+    keypair: Keypair,
+    rpc: RpcClient,
+    policy: Policy,
+}
+
+impl Relayer {
+    fn relay(&mut self, body: &str) -> Result<String, RelayError> {
+        let request: RelayRequest =
+            serde_json::from_str(body).map_err(|e| RelayError::Decode(e.to_string()))?;
+        let bytes = BASE64
+            .decode(request.transaction)
+            .map_err(|e| RelayError::Decode(e.to_string()))?;
+        let mut transaction: Transaction =
+            bincode::deserialize(&bytes).map_err(|e| RelayError::Decode(e.to_string()))?;
+
+        let approved = self.policy.check(&transaction, &self.rpc)?;
+
+        let blockhash = transaction.message.recent_blockhash;
+        transaction
+            .try_partial_sign(&[&self.keypair], blockhash)
+            .map_err(|e| RelayError::Decode(e.to_string()))?;
+        transaction
+            .verify()
+            .map_err(|_| RelayError::InvalidSignature)?;
+
+        let signature = self.rpc.send_transaction(&transaction)?;
+        self.policy.record(approved.participant);
+
+        println!(
+            "relayed {signature} for {} on contest {} (reimbursement {} lamports)",
+            approved.participant, approved.contest, approved.cost
+        );
+        Ok(signature)
+    }
+
+    fn handle(&mut self, mut request: Request) {
+        let (status, body) = match (request.method(), request.url()) {
+            (Method::Get, "/payer") => (200, json!({ "payer": self.keypair.pubkey().to_string() })),
+            (Method::Post, "/relay") => {
+                let mut body = String::new();
+                let read = request
+                    .as_reader()
+                    .take(MAX_BODY_LEN)
+                    .read_to_string(&mut body);
+                let result = match read {
+                    Ok(_) => self.relay(&body),
+                    Err(e) => Err(RelayError::Decode(e.to_string())),
+                };
+                match result {
+                    Ok(signature) => (200, json!({ "signature": signature })),
+                    Err(e) => {
+                        eprintln!("rejected: {e}");
+                        (e.status(), json!({ "error": e.to_string() }))
+                    }
+                }
+            }
+            _ => (404, json!({ "error": "not found" })),
+        };
+        respond(request, status, body);
+    }
+}
+
+fn respond(request: Request, status: u16, body: Value) {
+    let header = Header::from_bytes("Content-Type", "application/json").expect("static header");
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(header);
+    if let Err(e) = request.respond(response) {
+        eprintln!("failed to respond: {e}");
+    }
+}
+
+fn main() {
+    let config = match RelayerConfig::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    };
+    let keypair = read_keypair_file(&config.keypair_path).unwrap_or_else(|e| {
+        eprintln!("cannot read keypair {}: {e}", config.keypair_path.display());
+        std::process::exit(1);
+    });
+    let server = Server::http(&config.listen_addr).unwrap_or_else(|e| {
+        eprintln!("cannot listen on {}: {e}", config.listen_addr);
+        std::process::exit(1);
+    });
+
+    let mut relayer = Relayer {
+        policy: Policy::new(keypair.pubkey(), config.wallet_limit, config.wallet_window),
+        rpc: RpcClient::new(config.rpc_url),
+        keypair,
+    };
+    println!(
+        "SolArena relayer {} listening on {}",
+        relayer.keypair.pubkey(),
+        config.listen_addr
+    );
+
+    for request in server.incoming_requests() {
+        relayer.handle(request);
+    }
+}
@@ -0,0 +1,511 @@
+//! Which transactions the relayer is willing to pay for
+//!
+//! A relayed transaction must:
+//! - name the relayer as fee payer and carry exactly one other signer (the participant)
+//! - contain a single `submit_entry` or `update_submission` instruction with the
+//!   relayer as its `payer`, so the gas pool reimburses the relayer on-chain
+//! - not use the relayer in any other account position
+//! - target a contest with gas sponsorship enabled, not paused, whose gas pool
+//!   can cover the fee and rent
+//! - change something, if it is an update (no-op updates are not reimbursed)
+//! - come from the relayer the protocol config names, since only that relayer's
+//!   signature fee is reimbursed
+//! - stay within the participant's per-wallet limit
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, AnchorDeserialize, Discriminator};
+use solana_transaction::Transaction;
+use solarena::instruction::{SubmitEntry, UpdateSubmission};
+use solarena::state::{sponsored_tx_fee, Config, Contest, Submission, SubmissionMetadata};
+
+use crate::error::RelayError;
+use crate::rpc::Ledger;
+
+/// Account positions within a relayed instruction (Anchor account order)
+struct Layout {
+    contest: usize,
+    submission: usize,
+    participant: usize,
+    payer: usize,
+    gas_pool: usize,
+}
+
+const SUBMIT_ENTRY: Layout = Layout { contest: 1, submission: 2, participant: 4, payer: 5, gas_pool: 6 };
+const UPDATE_SUBMISSION: Layout = Layout { contest: 1, submission: 2, participant: 3, payer: 4, gas_pool: 5 };
+
+/// A transaction that passed every check
+pub struct Approved {
+    pub participant: Pubkey,
+    pub contest: Pubkey,
+    /// Lamports the gas pool will reimburse the relayer
+    pub cost: u64,
+}
+
+pub struct Policy {
+    relayer: Pubkey,
+    wallet_limit: u32,
+    wallet_window: Duration,
+    /// Per-wallet window start and transactions relayed in it (in memory only)
+    usage: HashMap<Pubkey, (Instant, u32)>,
+}
+
+impl Policy {
+    pub fn new(relayer: Pubkey, wallet_limit: u32, wallet_window: Duration) -> Self {
+        Self {
+            relayer,
+            wallet_limit,
+            wallet_window,
+            usage: HashMap::new(),
+        }
+    }
+
+    /// Checks a partially signed transaction against policy and on-chain state
+    pub fn check(&self, transaction: &Transaction, rpc: &impl Ledger) -> Result<Approved, RelayError> {
+        let message = &transaction.message;
+        if message.account_keys.first() != Some(&self.relayer) {
+            return Err(RelayError::FeePayerMismatch);
+        }
+        if message.header.num_required_signatures != 2 {
+            return Err(RelayError::SignerCount);
+        }
+        let [instruction] = message.instructions.as_slice() else {
+            return Err(RelayError::InstructionCount);
+        };
+        if message.account_keys.get(instruction.program_id_index as usize) != Some(&solarena::ID) {
+            return Err(RelayError::ForeignProgram);
+        }
+
+        let data = instruction.data.as_slice();
+        let (layout, new_size) = if data.starts_with(SubmitEntry::DISCRIMINATOR) {
+            let args = SubmitEntry::deserialize(&mut &data[8..])
+                .map_err(|e| RelayError::Decode(e.to_string()))?;
+            (SUBMIT_ENTRY, Some(Submission::space(args.submission_url.len(), &SubmissionMetadata::default())))
+        } else if data.starts_with(UpdateSubmission::DISCRIMINATOR) {
+            (UPDATE_SUBMISSION, None)
+        } else {
+            return Err(RelayError::UnsupportedInstruction);
+        };
+
+        let key = |position: usize| -> Result<Pubkey, RelayError> {
+            instruction
+                .accounts
+                .get(position)
+                .and_then(|index| message.account_keys.get(*index as usize))
+                .copied()
+                .ok_or_else(|| RelayError::Decode("missing instruction account".to_string()))
+        };
+        if key(layout.payer)? != self.relayer {
+            return Err(RelayError::RelayerMisused);
+        }
+        for (position, index) in instruction.accounts.iter().enumerate() {
+            if message.account_keys.get(*index as usize) == Some(&self.relayer) && position != layout.payer {
+                return Err(RelayError::RelayerMisused);
+            }
+        }
+
+        let participant = key(layout.participant)?;
+        if self.remaining(&participant) == 0 {
+            return Err(RelayError::WalletLimit);
+        }
+
+        let contest_key = key(layout.contest)?;
+        let contest = fetch::<Contest>(rpc, &contest_key)?;
+        if !contest.gas_sponsorship_enabled {
+            return Err(RelayError::SponsorshipDisabled);
+        }
+        if contest.paused {
+            return Err(RelayError::ContestPaused);
+        }
+
+        // Rent the relayer will front: the whole account on submit, any growth on update
+        let rent = match new_size {
+            Some(size) => rpc.minimum_balance(size)?,
+            None => {
+                let args = UpdateSubmission::deserialize(&mut &data[8..])
+                    .map_err(|e| RelayError::Decode(e.to_string()))?;
+                let submission = fetch::<Submission>(rpc, &key(layout.submission)?)?;
+                if !submission.update_changes(&args.new_url, &args.new_commitment, &args.metadata) {
+                    return Err(RelayError::NoChange);
+                }
+                let old_size = Submission::space(submission.submission_url.len(), &submission.metadata);
+                let new_size = submission.space_after_update(&args.new_url, &args.metadata);
+                rpc.minimum_balance(new_size)?
+                    .saturating_sub(rpc.minimum_balance(old_size)?)
+            }
+        };
+        let config = fetch::<Config>(rpc, &Pubkey::find_program_address(&[b"config"], &solarena::ID).0)?;
+        if config.relayer != Some(self.relayer) {
+            return Err(RelayError::Config("relayer is not the one named in the protocol config".to_string()));
+        }
+        let cost = sponsored_tx_fee(&self.relayer, &participant, config.relayer) + rent;
+
+        let gas_pool = key(layout.gas_pool)?;
+        let spendable = rpc
+            .get_balance(&gas_pool)?
+            .saturating_sub(rpc.minimum_balance(0)?);
+        if spendable < cost {
+            return Err(RelayError::GasPoolTooLow);
+        }
+
+        Ok(Approved {
+            participant,
+            contest: contest_key,
+            cost,
+        })
+    }
+
+    /// Counts a relayed transaction against the participant's limit
+    pub fn record(&mut self, participant: Pubkey) {
+        let now = Instant::now();
+        let entry = self.usage.entry(participant).or_insert((now, 0));
+        if now.duration_since(entry.0) >= self.wallet_window {
+            *entry = (now, 0);
+        }
+        entry.1 += 1;
+    }
+
+    fn remaining(&self, participant: &Pubkey) -> u32 {
+        match self.usage.get(participant) {
+            Some((start, count)) if start.elapsed() < self.wallet_window => {
+                self.wallet_limit.saturating_sub(*count)
+            }
+            _ => self.wallet_limit,
+        }
+    }
+}
+
+fn fetch<T: AccountDeserialize>(rpc: &impl Ledger, address: &Pubkey) -> Result<T, RelayError> {
+    let data = rpc
+        .get_account_data(address)?
+        .ok_or_else(|| RelayError::AccountNotFound(address.to_string()))?;
+    T::try_deserialize(&mut data.as_slice()).map_err(|e| RelayError::Decode(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::instruction::Instruction;
+    use anchor_lang::solana_program::system_instruction;
+    use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+    use solarena::state::{ContestStatus, SPONSORED_SIGNATURE_FEE};
+
+    /// In-memory ledger with a simplified rent schedule
+    #[derive(Default)]
+    struct FakeLedger {
+        accounts: HashMap<Pubkey, Vec<u8>>,
+        balances: HashMap<Pubkey, u64>,
+    }
+
+    impl Ledger for FakeLedger {
+        fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, RelayError> {
+            Ok(self.accounts.get(address).cloned())
+        }
+
+        fn get_balance(&self, address: &Pubkey) -> Result<u64, RelayError> {
+            Ok(self.balances.get(address).copied().unwrap_or(0))
+        }
+
+        fn minimum_balance(&self, data_len: usize) -> Result<u64, RelayError> {
+            Ok(1_000 + 10 * data_len as u64)
+        }
+    }
+
+    impl FakeLedger {
+        fn store<T: AccountSerialize>(&mut self, address: Pubkey, account: &T) {
+            let mut data = Vec::new();
+            account.try_serialize(&mut data).unwrap();
+            self.accounts.insert(address, data);
+        }
+    }
+
+    struct Fixture {
+        relayer: Pubkey,
+        participant: Pubkey,
+        contest: Pubkey,
+        submission: Pubkey,
+        gas_pool: Pubkey,
+        ledger: FakeLedger,
+    }
+
+    fn pda(seeds: &[&[u8]]) -> Pubkey {
+        Pubkey::find_program_address(seeds, &solarena::ID).0
+    }
+
+    /// A sponsored, funded contest whose config names `relayer`
+    fn fixture() -> Fixture {
+        let relayer = Pubkey::new_unique();
+        let participant = Pubkey::new_unique();
+        let contest = Pubkey::new_unique();
+        let submission = pda(&[b"submission", contest.as_ref(), participant.as_ref()]);
+        let gas_pool = pda(&[b"gas_pool", contest.as_ref()]);
+
+        let mut ledger = FakeLedger::default();
+        ledger.store(
+            pda(&[b"config"]),
+            &Config {
+                admin: Pubkey::new_unique(),
+                pending_admin: None,
+                min_prize_amount: 1_000_000,
+                max_judges: 5,
+                reclaim_delay: 3_600,
+                max_url_len: 200,
+                platform_fee_bps: 0,
+                relayer: Some(relayer),
+                paused: false,
+                bump: 255,
+            },
+        );
+        let mut state = Contest::new(Pubkey::new_unique(), 0, 255, 0);
+        state.status = ContestStatus::Active;
+        state.gas_sponsorship_enabled = true;
+        ledger.store(contest, &state);
+        ledger.balances.insert(gas_pool, 1_000_000);
+
+        Fixture { relayer, participant, contest, submission, gas_pool, ledger }
+    }
+
+    impl Fixture {
+        fn submit_entry(&self, url: &str) -> Instruction {
+            let accounts = solarena::accounts::SubmitEntry {
+                config: pda(&[b"config"]),
+                contest: self.contest,
+                submission: self.submission,
+                registration: None,
+                participant: self.participant,
+                payer: self.relayer,
+                gas_pool: self.gas_pool,
+                escrow: None,
+                fee_mint: None,
+                participant_fee_account: None,
+                escrow_fee_account: None,
+                token_program: None,
+                gate_token_account: None,
+                gate_nft_metadata: None,
+                system_program: anchor_lang::system_program::ID,
+                instructions: anchor_lang::solana_program::sysvar::instructions::ID,
+            };
+            let args = solarena::instruction::SubmitEntry {
+                submission_url: url.to_string(),
+                allowlist_proof: Vec::new(),
+                commitment: None,
+                track: 0,
+            };
+            Instruction::new_with_bytes(solarena::ID, &args.data(), accounts.to_account_metas(None))
+        }
+
+        fn update_submission(&self, new_url: &str) -> Instruction {
+            let accounts = solarena::accounts::UpdateSubmission {
+                config: pda(&[b"config"]),
+                contest: self.contest,
+                submission: self.submission,
+                participant: self.participant,
+                payer: self.relayer,
+                gas_pool: self.gas_pool,
+                system_program: anchor_lang::system_program::ID,
+                instructions: anchor_lang::solana_program::sysvar::instructions::ID,
+            };
+            let args = solarena::instruction::UpdateSubmission {
+                new_url: new_url.to_string(),
+                new_commitment: None,
+                metadata: None,
+            };
+            Instruction::new_with_bytes(solarena::ID, &args.data(), accounts.to_account_metas(None))
+        }
+
+        fn store_submission(&mut self, url: &str) {
+            let submission = Submission {
+                version: Submission::VERSION,
+                participant: self.participant,
+                contest: self.contest,
+                submission_url: url.to_string(),
+                submitted_at: 0,
+                last_modified: 0,
+                bump: 255,
+                entry_fee_paid: 0,
+                entry_fee_refunded: false,
+                commitment: None,
+                revealed: true,
+                track: 0,
+                disqualify_votes: 0,
+                disqualify_reason: [0; 32],
+                disqualified: false,
+                lateness: 0,
+                rent_sponsored: 0,
+                metadata: SubmissionMetadata::default(),
+            };
+            self.ledger.store(self.submission, &submission);
+        }
+
+        fn transaction(&self, instructions: &[Instruction]) -> Transaction {
+            Transaction::new_with_payer(instructions, Some(&self.relayer))
+        }
+
+        fn update_contest(&mut self, update: impl FnOnce(&mut Contest)) {
+            let data = self.ledger.accounts[&self.contest].clone();
+            let mut contest = Contest::try_deserialize(&mut data.as_slice()).unwrap();
+            update(&mut contest);
+            self.ledger.store(self.contest, &contest);
+        }
+    }
+
+    fn policy(f: &Fixture) -> Policy {
+        Policy::new(f.relayer, 2, Duration::from_secs(3_600))
+    }
+
+    #[test]
+    fn layouts_match_the_program_account_order() {
+        let f = fixture();
+        let submit = f.submit_entry("https://example.com/a");
+        assert_eq!(submit.accounts[SUBMIT_ENTRY.contest].pubkey, f.contest);
+        assert_eq!(submit.accounts[SUBMIT_ENTRY.submission].pubkey, f.submission);
+        assert_eq!(submit.accounts[SUBMIT_ENTRY.participant].pubkey, f.participant);
+        assert_eq!(submit.accounts[SUBMIT_ENTRY.payer].pubkey, f.relayer);
+        assert_eq!(submit.accounts[SUBMIT_ENTRY.gas_pool].pubkey, f.gas_pool);
+
+        let update = f.update_submission("https://example.com/b");
+        assert_eq!(update.accounts[UPDATE_SUBMISSION.contest].pubkey, f.contest);
+        assert_eq!(update.accounts[UPDATE_SUBMISSION.submission].pubkey, f.submission);
+        assert_eq!(update.accounts[UPDATE_SUBMISSION.participant].pubkey, f.participant);
+        assert_eq!(update.accounts[UPDATE_SUBMISSION.payer].pubkey, f.relayer);
+        assert_eq!(update.accounts[UPDATE_SUBMISSION.gas_pool].pubkey, f.gas_pool);
+    }
+
+    #[test]
+    fn approves_a_sponsored_submit_entry() {
+        let f = fixture();
+        let url = "https://example.com/a";
+        let approved = policy(&f)
+            .check(&f.transaction(&[f.submit_entry(url)]), &f.ledger)
+            .unwrap();
+
+        let size = Submission::space(url.len(), &SubmissionMetadata::default());
+        assert_eq!(approved.participant, f.participant);
+        assert_eq!(approved.contest, f.contest);
+        assert_eq!(
+            approved.cost,
+            2 * SPONSORED_SIGNATURE_FEE + f.ledger.minimum_balance(size).unwrap()
+        );
+    }
+
+    #[test]
+    fn approves_an_update_that_changes_the_url() {
+        let mut f = fixture();
+        f.store_submission("https://example.com/a");
+        let approved = policy(&f)
+            .check(&f.transaction(&[f.update_submission("https://example.com/longer")]), &f.ledger)
+            .unwrap();
+        // Five more URL bytes at ten lamports each
+        assert_eq!(approved.cost, 2 * SPONSORED_SIGNATURE_FEE + 50);
+    }
+
+    #[test]
+    fn rejects_no_op_updates() {
+        let mut f = fixture();
+        f.store_submission("https://example.com/a");
+        let result = policy(&f).check(&f.transaction(&[f.update_submission("")]), &f.ledger);
+        assert!(matches!(result, Err(RelayError::NoChange)));
+    }
+
+    #[test]
+    fn rejects_foreign_and_extra_instructions() {
+        let f = fixture();
+        let transfer = system_instruction::transfer(&f.relayer, &f.participant, 1);
+        let result = policy(&f).check(&f.transaction(&[transfer]), &f.ledger);
+        assert!(matches!(result, Err(RelayError::SignerCount)));
+
+        // A foreign instruction signed by the participant passes the signer check
+        let drain = system_instruction::transfer(&f.participant, &f.relayer, 1);
+        let result = policy(&f).check(&f.transaction(&[drain]), &f.ledger);
+        assert!(matches!(result, Err(RelayError::ForeignProgram)));
+
+        let submit = f.submit_entry("https://example.com/a");
+        let result = policy(&f).check(&f.transaction(&[submit.clone(), submit]), &f.ledger);
+        assert!(matches!(result, Err(RelayError::InstructionCount)));
+
+        let mut other = f.submit_entry("https://example.com/a");
+        other.data = solarena::instruction::FundContest {}.data();
+        let result = policy(&f).check(&f.transaction(&[other]), &f.ledger);
+        assert!(matches!(result, Err(RelayError::UnsupportedInstruction)));
+    }
+
+    #[test]
+    fn rejects_other_fee_payers_and_relayer_misuse() {
+        let f = fixture();
+        let submit = f.submit_entry("https://example.com/a");
+        let transaction = Transaction::new_with_payer(std::slice::from_ref(&submit), Some(&f.participant));
+        let result = policy(&f).check(&transaction, &f.ledger);
+        assert!(matches!(result, Err(RelayError::FeePayerMismatch)));
+
+        let mut misused = submit;
+        misused.accounts[SUBMIT_ENTRY.gas_pool].pubkey = f.relayer;
+        let result = policy(&f).check(&f.transaction(&[misused]), &f.ledger);
+        assert!(matches!(result, Err(RelayError::RelayerMisused)));
+    }
+
+    #[test]
+    fn rejects_unsponsored_paused_and_underfunded_contests() {
+        let transaction = |f: &Fixture| f.transaction(&[f.submit_entry("https://example.com/a")]);
+
+        let mut f = fixture();
+        f.update_contest(|contest| contest.gas_sponsorship_enabled = false);
+        let result = policy(&f).check(&transaction(&f), &f.ledger);
+        assert!(matches!(result, Err(RelayError::SponsorshipDisabled)));
+
+        let mut f = fixture();
+        f.update_contest(|contest| contest.paused = true);
+        let result = policy(&f).check(&transaction(&f), &f.ledger);
+        assert!(matches!(result, Err(RelayError::ContestPaused)));
+
+        let mut f = fixture();
+        f.ledger.balances.insert(f.gas_pool, 1_500);
+        let result = policy(&f).check(&transaction(&f), &f.ledger);
+        assert!(matches!(result, Err(RelayError::GasPoolTooLow)));
+    }
+
+    #[test]
+    fn rejects_a_relayer_the_config_does_not_name() {
+        let f = fixture();
+        let stranger = Pubkey::new_unique();
+        let mut submit = f.submit_entry("https://example.com/a");
+        submit.accounts[SUBMIT_ENTRY.payer].pubkey = stranger;
+        let transaction = Transaction::new_with_payer(&[submit], Some(&stranger));
+        let result = Policy::new(stranger, 2, Duration::from_secs(3_600)).check(&transaction, &f.ledger);
+        assert!(matches!(result, Err(RelayError::Config(_))));
+    }
+
+    #[test]
+    fn enforces_the_per_wallet_limit() {
+        let f = fixture();
+        let mut policy = policy(&f);
+        let transaction = f.transaction(&[f.submit_entry("https://example.com/a")]);
+
+        assert_eq!(policy.remaining(&f.participant), 2);
+        policy.record(f.participant);
+        assert_eq!(policy.remaining(&f.participant), 1);
+        assert!(policy.check(&transaction, &f.ledger).is_ok());
+
+        policy.record(f.participant);
+        assert_eq!(policy.remaining(&f.participant), 0);
+        assert!(matches!(policy.check(&transaction, &f.ledger), Err(RelayError::WalletLimit)));
+
+        // Limits are per participant
+        assert_eq!(policy.remaining(&Pubkey::new_unique()), 2);
+    }
+
+    #[test]
+    fn wallet_limit_resets_after_the_window() {
+        let f = fixture();
+        let mut policy = Policy::new(f.relayer, 1, Duration::from_millis(20));
+
+        policy.record(f.participant);
+        assert_eq!(policy.remaining(&f.participant), 0);
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(policy.remaining(&f.participant), 1);
+        policy.record(f.participant);
+        assert_eq!(policy.remaining(&f.participant), 0);
+    }
+}
@@ -0,0 +1,96 @@
+//! Minimal JSON-RPC client for the calls the relayer needs
+
+use anchor_lang::prelude::Pubkey;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_transaction::Transaction;
+
+use crate::error::RelayError;
+
+/// Account reads the relay policy depends on
+pub trait Ledger {
+    /// Account data, or `None` if the account does not exist
+    fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, RelayError>;
+
+    fn get_balance(&self, address: &Pubkey) -> Result<u64, RelayError>;
+
+    fn minimum_balance(&self, data_len: usize) -> Result<u64, RelayError>;
+}
+
+pub struct RpcClient {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl RpcClient {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value, RelayError> {
+        let response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+            .map_err(|e| RelayError::Rpc(e.to_string()))?
+            .into_json()
+            .map_err(|e| RelayError::Rpc(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(RelayError::Rpc(error.to_string()));
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| RelayError::Rpc(format!("{method}: missing result")))
+    }
+
+    /// Submits a fully signed transaction (with preflight) and returns its signature
+    pub fn send_transaction(&self, transaction: &Transaction) -> Result<String, RelayError> {
+        let bytes = bincode::serialize(transaction).map_err(|e| RelayError::Decode(e.to_string()))?;
+        self.call(
+            "sendTransaction",
+            json!([BASE64.encode(bytes), { "encoding": "base64", "preflightCommitment": "confirmed" }]),
+        )?
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| RelayError::Rpc("sendTransaction: unexpected result".to_string()))
+    }
+}
+
+impl Ledger for RpcClient {
+    fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, RelayError> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+        let Some(data) = result["value"]["data"][0].as_str() else {
+            return Ok(None);
+        };
+        BASE64
+            .decode(data)
+            .map(Some)
+            .map_err(|e| RelayError::Rpc(e.to_string()))
+    }
+
+    fn get_balance(&self, address: &Pubkey) -> Result<u64, RelayError> {
+        let result = self.call(
+            "getBalance",
+            json!([address.to_string(), { "commitment": "confirmed" }]),
+        )?;
+        result["value"]
+            .as_u64()
+            .ok_or_else(|| RelayError::Rpc("getBalance: unexpected result".to_string()))
+    }
+
+    fn minimum_balance(&self, data_len: usize) -> Result<u64, RelayError> {
+        self.call("getMinimumBalanceForRentExemption", json!([data_len]))?
+            .as_u64()
+            .ok_or_else(|| RelayError::Rpc("getMinimumBalanceForRentExemption: unexpected result".to_string()))
+    }
+
+}
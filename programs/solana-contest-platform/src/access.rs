@@ -0,0 +1,53 @@
+//! Access-control checks run before instruction handlers
+//!
+//! # Anchor Concepts:
+//! - #[access_control(...)] on a program function runs these checks against
+//!   the deserialized accounts before the handler body
+//! - Each instruction opts in explicitly, so which ones stop during a pause,
+//!   and which permission an operator needs, is visible right in lib.rs
+
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::state::{Config, Contest, ContestRole};
+
+/// Blocks the instruction while the protocol is paused by the admin
+pub fn protocol_not_paused(config: &Config) -> Result<()> {
+    require!(!config.paused, ErrorCode::ProtocolPaused);
+    Ok(())
+}
+
+/// Blocks the instruction while the protocol or this contest is paused
+pub fn contest_not_paused(config: &Config, contest: &Contest) -> Result<()> {
+    protocol_not_paused(config)?;
+    require!(!contest.paused, ErrorCode::ContestPaused);
+    Ok(())
+}
+
+/// Whether `signer` is the contest authority or holds a role with `permission`
+pub fn has_permission(
+    contest: &Account<Contest>,
+    signer: &Pubkey,
+    role: Option<&ContestRole>,
+    permission: u8,
+) -> bool {
+    if *signer == contest.authority {
+        return true;
+    }
+    role.is_some_and(|r| {
+        r.contest == contest.key() && r.delegate == *signer && r.has(permission)
+    })
+}
+
+/// Requires the operator to be the contest authority or a delegate with `permission`
+pub fn authorize(
+    contest: &Account<Contest>,
+    operator: &Signer,
+    role: &Option<Account<ContestRole>>,
+    permission: u8,
+) -> Result<()> {
+    require!(
+        has_permission(contest, &operator.key(), role.as_deref(), permission),
+        ErrorCode::MissingPermission
+    );
+    Ok(())
+}
@@ -17,16 +17,16 @@ pub enum ErrorCode {
     #[msg("Description must be 500 characters or less")]
     DescriptionTooLong,
 
-    #[msg("Maximum 5 judges allowed")]
+    #[msg("Too many judges for the configured limit")]
     TooManyJudges,
 
     #[msg("At least one judge required")]
     NoJudges,
 
-    #[msg("Approval threshold cannot exceed number of judges")]
+    #[msg("Approval threshold must be between 1 and the number of judges")]
     InvalidThreshold,
 
-    #[msg("Prize must be at least 0.01 SOL (10,000,000 lamports)")]
+    #[msg("Prize is below the configured minimum")]
     PrizeTooLow,
 
     #[msg("Deadline must be in the future")]
@@ -41,7 +41,7 @@ pub enum ErrorCode {
     #[msg("Calculation overflow occurred")]
     CalculationOverflow,
 
-    #[msg("URL must be 200 characters or less")]
+    #[msg("URL exceeds the configured length limit")]
     UrlTooLong,
 
     #[msg("URL must start with https://")]
@@ -53,7 +53,7 @@ pub enum ErrorCode {
     #[msg("Only the original participant can update this submission")]
     UnauthorizedParticipant,
 
-    #[msg("Only the contest creator can perform this action")]
+    #[msg("Only the contest authority can perform this action")]
     UnauthorizedCreator,
 
     #[msg("Only authorized judges can vote")]
@@ -73,4 +73,232 @@ pub enum ErrorCode {
 
     #[msg("Reclaim period not yet reached")]
     ReclaimPeriodNotReached,
+
+    #[msg("Registration deadline must be in the future and before the submission deadline")]
+    InvalidRegistrationDeadline,
+
+    #[msg("This contest does not use registration")]
+    RegistrationNotEnabled,
+
+    #[msg("Registration window has closed")]
+    RegistrationClosed,
+
+    #[msg("Submissions are not open yet")]
+    SubmissionsNotOpen,
+
+    #[msg("Participant must register before submitting")]
+    NotRegistered,
+
+    #[msg("Contest has reached its participant limit")]
+    ParticipantCapReached,
+
+    #[msg("Prize share must be at most 10,000 basis points")]
+    InvalidFeeSplit,
+
+    #[msg("Lamport entry fee must cover the escrow rent-exempt minimum")]
+    EntryFeeTooLow,
+
+    #[msg("Fee accounts required for this contest's entry fee were not provided")]
+    MissingFeeAccounts,
+
+    #[msg("Fee mint does not match the contest's entry fee mint")]
+    InvalidFeeMint,
+
+    #[msg("Winner does not match the contest result")]
+    InvalidWinner,
+
+    #[msg("No entry fees to settle")]
+    NoEntryFees,
+
+    #[msg("Entry fee already refunded")]
+    EntryFeeAlreadyRefunded,
+
+    #[msg("Participant is not on the contest allowlist")]
+    NotOnAllowlist,
+
+    #[msg("Participant does not meet the contest's token or NFT requirement")]
+    GateRequirementNotMet,
+
+    #[msg("NFT metadata account is invalid")]
+    InvalidNftMetadata,
+
+    #[msg("Token gate minimum amount must be greater than zero")]
+    InvalidGate,
+
+    #[msg("Reveal period cannot be negative")]
+    InvalidRevealPeriod,
+
+    #[msg("This contest does not use sealed submissions")]
+    SubmissionsNotSealed,
+
+    #[msg("Sealed contests take a commitment instead of a URL; open contests take a URL only")]
+    InvalidSubmissionMode,
+
+    #[msg("Reveal window has not opened yet")]
+    RevealWindowNotOpen,
+
+    #[msg("Reveal window has closed")]
+    RevealWindowClosed,
+
+    #[msg("Submission already revealed")]
+    AlreadyRevealed,
+
+    #[msg("Revealed URL and salt do not match the commitment")]
+    CommitmentMismatch,
+
+    #[msg("Reveal window has not ended yet")]
+    RevealPeriodNotEnded,
+
+    #[msg("Submission was never revealed and cannot win")]
+    SubmissionNotRevealed,
+
+    #[msg("A contest must have between 1 and 5 tracks")]
+    InvalidTrackCount,
+
+    #[msg("Track name must be 32 characters or less")]
+    TrackNameTooLong,
+
+    #[msg("Track index out of range")]
+    InvalidTrack,
+
+    #[msg("Prize for this track has already been distributed")]
+    TrackAlreadyDistributed,
+
+    #[msg("Winner's submission is not in this track")]
+    WrongTrack,
+
+    #[msg("Entry fee prize share is not supported for track contests")]
+    FeeShareWithTracks,
+
+    #[msg("Submission has already been disqualified")]
+    AlreadyDisqualified,
+
+    #[msg("Disqualification reason does not match the one already under vote")]
+    DisqualificationReasonMismatch,
+
+    #[msg("Submission was disqualified and cannot win")]
+    SubmissionDisqualified,
+
+    #[msg("Late period cannot be negative")]
+    InvalidLatePeriod,
+
+    #[msg("Project title must be 64 characters or less")]
+    ProjectTitleTooLong,
+
+    #[msg("Submission description must be 280 characters or less")]
+    SubmissionDescriptionTooLong,
+
+    #[msg("Milestones must be added in order, up to 10 per contest")]
+    InvalidMilestoneIndex,
+
+    #[msg("Milestone amount must cover the escrow rent-exempt minimum")]
+    InvalidMilestoneAmount,
+
+    #[msg("Milestone amounts must add up to the prize amount")]
+    MilestoneTotalMismatch,
+
+    #[msg("Milestones cannot be combined with tracks")]
+    MilestonesWithTracks,
+
+    #[msg("Milestone has already been released")]
+    MilestoneAlreadyReleased,
+
+    #[msg("Winner has not submitted proof for this milestone")]
+    MilestoneProofMissing,
+
+    #[msg("A winner has already been selected")]
+    WinnerAlreadySelected,
+
+    #[msg("Only the description and metadata link can change once participants have joined")]
+    MaterialFieldLocked,
+
+    #[msg("Prize of a track contest is set by its tracks")]
+    PrizeManagedByTracks,
+
+    #[msg("New deadline must be later than the current deadline")]
+    DeadlineNotLater,
+
+    #[msg("Total deadline extension cannot exceed 30 days")]
+    ExtensionLimitExceeded,
+
+    #[msg("Metadata URI must be 200 characters or less")]
+    MetadataUriTooLong,
+
+    #[msg("Metadata URI must start with https://, ipfs:// or ar://")]
+    InvalidMetadataUri,
+
+    #[msg("Metadata URI and hash must be set or cleared together")]
+    MetadataHashRequired,
+
+    #[msg("Contest must be completed or cancelled and past its 7-day dispute window")]
+    ContestNotClosable,
+
+    #[msg("Entry fee must be refunded before the submission can be closed")]
+    EntryFeeRefundPending,
+
+    #[msg("Registrations, submissions, votes, milestones, and roles must be closed first")]
+    AccountsStillOpen,
+
+    #[msg("Entry fees must be settled or refunded before the contest can be closed")]
+    EntryFeesOutstanding,
+
+    #[msg("Series rounds need a positive duration and interval")]
+    InvalidSeriesSchedule,
+
+    #[msg("The next series round is not due yet")]
+    SeriesRoundNotDue,
+
+    #[msg("Series has opened all of its rounds")]
+    SeriesFinished,
+
+    #[msg("Series budget cannot fund the next round")]
+    SeriesBudgetExhausted,
+
+    #[msg("Submission start must be in the future and before the deadline")]
+    InvalidSubmissionStart,
+
+    #[msg("Config values are out of range")]
+    InvalidConfig,
+
+    #[msg("Only the protocol admin can perform this action")]
+    UnauthorizedAdmin,
+
+    #[msg("Signer is not the proposed admin")]
+    NotPendingAdmin,
+
+    #[msg("Only the program upgrade authority can initialize the config")]
+    NotUpgradeAuthority,
+
+    #[msg("Treasury balance (above its rent-exempt minimum) is too low")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+
+    #[msg("Contest is paused")]
+    ContestPaused,
+
+    #[msg("Signer is not the proposed contest authority")]
+    NotPendingAuthority,
+
+    #[msg("Signer is neither the contest authority nor a delegate with this permission")]
+    MissingPermission,
+
+    #[msg("Role permissions must be a non-empty set of known permissions")]
+    InvalidPermissions,
+
+    #[msg("Account layout version is not supported")]
+    UnsupportedAccountVersion,
+
+    #[msg("Account passed for migration does not belong to this contest")]
+    InvalidMigrationAccount,
+
+    #[msg("Deadline of a funded contest can only move later through extend_deadline")]
+    DeadlineLocked,
+
+    #[msg("Judge already voted before migration")]
+    AlreadyVoted,
+
+    #[msg("Every judge's version 0 vote address must be passed when migrating a version 0 contest")]
+    MissingLegacyVote,
 }
@@ -0,0 +1,102 @@
+//! Events emitted for off-chain indexers and clients
+//!
+//! # Anchor Concepts:
+//! - #[event] macro derives serialization and a unique discriminator
+//! - emit!(...) writes the event to the program logs
+//!
+//! Reference: https://www.anchor-lang.com/docs/features/events
+
+use anchor_lang::prelude::*;
+
+/// Emitted whenever a contest's participant allowlist changes
+#[event]
+pub struct AllowlistRootUpdated {
+    pub contest: Pubkey,
+    pub old_root: Option<[u8; 32]>,
+    pub new_root: Option<[u8; 32]>,
+    pub updated_at: i64,
+}
+
+/// Emitted on every `update_contest`
+#[event]
+pub struct ContestUpdated {
+    pub contest: Pubkey,
+    pub revision: u32,
+    pub material: bool,
+    pub updated_at: i64,
+}
+
+/// Emitted when the creator extends the submission deadline
+#[event]
+pub struct DeadlineExtended {
+    pub contest: Pubkey,
+    pub old_deadline: i64,
+    pub new_deadline: i64,
+    pub total_extension: i64,
+    pub reclaim_available_at: i64,
+}
+
+/// Emitted when judges reach quorum to disqualify a submission
+#[event]
+pub struct SubmissionDisqualified {
+    pub contest: Pubkey,
+    pub participant: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub approvals: u8,
+    pub disqualified_at: i64,
+}
+
+/// Emitted when judges approve a milestone and its amount is paid out
+#[event]
+pub struct MilestoneReleased {
+    pub contest: Pubkey,
+    pub index: u8,
+    pub winner: Pubkey,
+    pub amount: u64,
+    pub platform_fee: u64,
+    pub proof_hash: [u8; 32],
+    pub released_at: i64,
+}
+
+/// Emitted when the admin changes protocol policy
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub min_prize_amount: u64,
+    pub max_judges: u8,
+    pub reclaim_delay: i64,
+    pub max_url_len: u16,
+    pub platform_fee_bps: u16,
+    pub relayer: Option<Pubkey>,
+}
+
+/// Emitted when a proposed admin accepts control of the config
+#[event]
+pub struct AdminTransferred {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+/// Emitted when the protocol (`contest` = None) or a contest is paused or resumed
+#[event]
+pub struct PauseUpdated {
+    pub contest: Option<Pubkey>,
+    pub paused: bool,
+    pub updated_at: i64,
+}
+
+/// Emitted when a proposed contest authority accepts the handover
+#[event]
+pub struct AuthorityTransferred {
+    pub contest: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+/// Emitted when an operator role is granted, changed, or revoked (`permissions` = 0)
+#[event]
+pub struct RoleUpdated {
+    pub contest: Pubkey,
+    pub delegate: Pubkey,
+    pub permissions: u8,
+}
@@ -0,0 +1,104 @@
+//! Token- and NFT-gated participation checks
+//!
+//! Collection membership is read straight from the Metaplex Token Metadata
+//! account, so the program does not need the full mpl-token-metadata crate.
+//! Only the leading fields up to `collection` are decoded.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::errors::ErrorCode;
+use crate::state::ParticipationGate;
+
+/// Metaplex Token Metadata program
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// `Key::MetadataV1` discriminant in the Token Metadata program
+const METADATA_V1_KEY: u8 = 4;
+
+#[derive(AnchorDeserialize)]
+struct MetadataCreator {
+    _address: Pubkey,
+    _verified: bool,
+    _share: u8,
+}
+
+#[derive(AnchorDeserialize)]
+struct MetadataCollection {
+    verified: bool,
+    key: Pubkey,
+}
+
+/// Leading fields of a Token Metadata account, up to the collection
+#[derive(AnchorDeserialize)]
+struct MetadataPrefix {
+    key: u8,
+    _update_authority: Pubkey,
+    mint: Pubkey,
+    _name: String,
+    _symbol: String,
+    _uri: String,
+    _seller_fee_basis_points: u16,
+    _creators: Option<Vec<MetadataCreator>>,
+    _primary_sale_happened: bool,
+    _is_mutable: bool,
+    _edition_nonce: Option<u8>,
+    _token_standard: Option<u8>,
+    collection: Option<MetadataCollection>,
+}
+
+/// Verifies that `participant` satisfies the contest's participation gate
+///
+/// `token_account` must be owned by the participant (checked by the
+/// caller's account constraints). `nft_metadata` is only needed for
+/// collection gates.
+pub fn check_participation_gate(
+    gate: &Option<ParticipationGate>,
+    token_account: Option<&InterfaceAccount<TokenAccount>>,
+    nft_metadata: Option<&AccountInfo>,
+) -> Result<()> {
+    let Some(gate) = gate else {
+        return Ok(());
+    };
+    let token_account = token_account.ok_or(ErrorCode::GateRequirementNotMet)?;
+
+    match gate {
+        ParticipationGate::TokenBalance { mint, min_amount } => {
+            require_keys_eq!(token_account.mint, *mint, ErrorCode::GateRequirementNotMet);
+            require!(
+                token_account.amount >= *min_amount,
+                ErrorCode::GateRequirementNotMet
+            );
+        }
+        ParticipationGate::NftCollection { collection } => {
+            require!(token_account.amount >= 1, ErrorCode::GateRequirementNotMet);
+
+            let metadata = nft_metadata.ok_or(ErrorCode::GateRequirementNotMet)?;
+            let (expected_metadata, _) = Pubkey::find_program_address(
+                &[
+                    b"metadata",
+                    TOKEN_METADATA_PROGRAM_ID.as_ref(),
+                    token_account.mint.as_ref(),
+                ],
+                &TOKEN_METADATA_PROGRAM_ID,
+            );
+            require_keys_eq!(metadata.key(), expected_metadata, ErrorCode::InvalidNftMetadata);
+            require_keys_eq!(
+                *metadata.owner,
+                TOKEN_METADATA_PROGRAM_ID,
+                ErrorCode::InvalidNftMetadata
+            );
+
+            let data = metadata.try_borrow_data()?;
+            let parsed = MetadataPrefix::deserialize(&mut &data[..])
+                .map_err(|_| error!(ErrorCode::InvalidNftMetadata))?;
+            require!(parsed.key == METADATA_V1_KEY, ErrorCode::InvalidNftMetadata);
+            require_keys_eq!(parsed.mint, token_account.mint, ErrorCode::InvalidNftMetadata);
+
+            let in_collection = parsed
+                .collection
+                .is_some_and(|c| c.verified && c.key == *collection);
+            require!(in_collection, ErrorCode::GateRequirementNotMet);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::AdminTransferred;
+
+/// Accepts a proposed admin transfer (step 2 of 2)
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Two-step authority transfer** - The proposed key must sign to take over
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let new_admin = ctx.accounts.new_admin.key();
+
+    require!(
+        config.pending_admin == Some(new_admin),
+        ErrorCode::NotPendingAdmin
+    );
+
+    emit!(AdminTransferred {
+        old_admin: config.admin,
+        new_admin,
+    });
+
+    config.admin = new_admin;
+    config.pending_admin = None;
+
+    msg!("Config admin is now {}", new_admin);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub new_admin: Signer<'info>,
+}
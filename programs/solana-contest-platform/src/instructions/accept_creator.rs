@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::AuthorityTransferred;
+
+/// Accepts a proposed contest handover (step 2 of 2)
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Two-step authority transfer** - The proposed key must sign to take over
+pub fn accept_creator(ctx: Context<AcceptCreator>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let new_authority = ctx.accounts.new_authority.key();
+
+    require!(
+        contest.pending_authority == Some(new_authority),
+        ErrorCode::NotPendingAuthority
+    );
+
+    emit!(AuthorityTransferred {
+        contest: contest.key(),
+        old_authority: contest.authority,
+        new_authority,
+    });
+
+    contest.authority = new_authority;
+    contest.pending_authority = None;
+
+    msg!("Contest {} authority is now {}", contest.contest_id, new_authority);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptCreator<'info> {
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    pub new_authority: Signer<'info>,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Adds a payout milestone to a bounty contest
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Indexed PDAs** - Milestone seeds include a sequential index
+/// 2. **has_one constraint** - Only the contest authority can define milestones
+/// 3. **Setup-only configuration** - Milestones are frozen once funded
+///
+/// `fund_contest` requires the milestone amounts to add up to the prize.
+pub fn add_milestone(
+    ctx: Context<AddMilestone>,
+    index: u8,
+    description_hash: [u8; 32],
+    amount: u64,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let milestone = &mut ctx.accounts.milestone;
+
+    require!(
+        contest.status == ContestStatus::Setup,
+        ErrorCode::InvalidContestState
+    );
+    require!(contest.tracks.is_empty(), ErrorCode::MilestonesWithTracks);
+    require!(
+        index == contest.milestone_count && index < MAX_MILESTONES,
+        ErrorCode::InvalidMilestoneIndex
+    );
+    // Whatever remains in escrow is at least one milestone, keeping it rent-exempt
+    let rent_minimum = Rent::get()?.minimum_balance(0);
+    require!(amount >= rent_minimum, ErrorCode::InvalidMilestoneAmount);
+
+    milestone.contest = contest.key();
+    milestone.index = index;
+    milestone.description_hash = description_hash;
+    milestone.amount = amount;
+    milestone.proof_hash = None;
+    milestone.approvals = 0;
+    milestone.released = false;
+    milestone.bump = ctx.bumps.milestone;
+
+    contest.milestone_count += 1;
+    contest.open_accounts = contest.open_accounts.saturating_add(1);
+    contest.milestone_total = contest
+        .milestone_total
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationOverflow)?;
+
+    msg!("Milestone {} added: {} lamports", index, amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct AddMilestone<'info> {
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Milestone::INIT_SPACE,
+        seeds = [b"milestone", contest.key().as_ref(), &[index]],
+        bump
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::MilestoneReleased;
+use crate::utils::transfer_lamports_from_pda;
+
+/// Judge approves a milestone proof, releasing it at threshold
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Bitmask voting** - Each judge's index is one bit, so repeat approvals are no-ops
+/// 2. **PDA Signing** - Escrow PDA signs the milestone payout
+/// 3. **Partial escrow release** - Each milestone pays only its own amount
+pub fn approve_milestone(ctx: Context<ApproveMilestone>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let milestone = &mut ctx.accounts.milestone;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.status == ContestStatus::Active,
+        ErrorCode::InvalidContestState
+    );
+    require!(!milestone.released, ErrorCode::MilestoneAlreadyReleased);
+    let proof_hash = milestone.proof_hash.ok_or(ErrorCode::MilestoneProofMissing)?;
+
+    // Runtime check: Verify judge is in authorized judges list
+    let judge_index = contest
+        .judges
+        .iter()
+        .position(|j| *j == ctx.accounts.judge.key())
+        .ok_or(ErrorCode::UnauthorizedJudge)?;
+    milestone.approvals |= 1 << judge_index;
+
+    let approvals = milestone.approvals.count_ones() as u8;
+    if approvals < contest.approval_threshold {
+        msg!(
+            "Milestone {} approval recorded ({}/{})",
+            milestone.index,
+            approvals,
+            contest.approval_threshold
+        );
+        return Ok(());
+    }
+
+    // Threshold reached - release this milestone from escrow, less the platform fee
+    let platform_fee = contest.platform_fee(milestone.amount);
+    let contest_id = contest.contest_id.to_le_bytes();
+    let escrow_seeds: &[&[u8]] = &[
+        b"escrow",
+        contest.creator.as_ref(),
+        &contest_id,
+        &[ctx.bumps.escrow],
+    ];
+    transfer_lamports_from_pda(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.winner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[escrow_seeds],
+        milestone.amount - platform_fee,
+    )?;
+    transfer_lamports_from_pda(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[escrow_seeds],
+        platform_fee,
+    )?;
+    milestone.released = true;
+
+    emit!(MilestoneReleased {
+        contest: contest.key(),
+        index: milestone.index,
+        winner: ctx.accounts.winner.key(),
+        amount: milestone.amount,
+        platform_fee,
+        proof_hash,
+        released_at: clock.unix_timestamp,
+    });
+
+    let contest = &mut ctx.accounts.contest;
+    contest.milestones_released += 1;
+    if contest.milestones_released == contest.milestone_count {
+        contest.settle(ContestStatus::Completed, clock.unix_timestamp);
+    }
+
+    msg!("Milestone {} released", milestone.index);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveMilestone<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        has_one = contest,
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", contest.creator.as_ref(), &contest.contest_id.to_le_bytes()],
+        bump
+    )]
+    /// CHECK: Escrow PDA validated by seeds
+    pub escrow: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the winner recorded by distribute_prizes
+    #[account(
+        mut,
+        constraint = contest.winner == Some(winner.key()) @ ErrorCode::InvalidWinner
+    )]
+    pub winner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    /// CHECK: Treasury PDA validated by seeds
+    pub treasury: UncheckedAccount<'info>,
+
+    pub judge: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
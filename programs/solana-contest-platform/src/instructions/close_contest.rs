@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::utils::transfer_lamports_from_pda;
+
+/// Closes a settled contest, returning its rent to the creator and sweeping
+/// escrow and gas pool leftovers to the authority
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **close constraint** - Contest rent goes back to the creator who paid it,
+///    even after the contest has been handed over; leftover funds belong to
+///    whoever manages the contest now
+/// 2. **Child account tracking** - `open_accounts` must reach zero first, so no
+///    registration, submission, vote, milestone, or role is stranded without its contest
+/// 3. **PDA Signing** - Escrow and gas pool PDAs sign their final sweeps, and the
+///    escrow signs the `close_account` CPI for a token contest's fee account
+pub fn close_contest(ctx: Context<CloseContest>) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.is_closable(clock.unix_timestamp),
+        ErrorCode::ContestNotClosable
+    );
+    require!(contest.open_accounts == 0, ErrorCode::AccountsStillOpen);
+    // Entry fees must be settled (Completed) or refunded (Cancelled) first
+    require!(contest.entry_fee_pool == 0, ErrorCode::EntryFeesOutstanding);
+
+    let contest_key = contest.key();
+    let contest_id = contest.contest_id.to_le_bytes();
+    let escrow_seeds: &[&[u8]] = &[
+        b"escrow",
+        contest.creator.as_ref(),
+        &contest_id,
+        &[ctx.bumps.escrow],
+    ];
+    let escrow_signer: &[&[&[u8]]] = &[escrow_seeds];
+
+    // Token contests: the escrow's fee account can only be closed by the escrow
+    // PDA, so close it here (after sweeping any stray tokens to the authority)
+    if let Some(fee_mint_key) = contest.entry_fee_mint {
+        let (Some(fee_mint), Some(escrow_fee_account), Some(token_program)) = (
+            ctx.accounts.fee_mint.as_ref(),
+            ctx.accounts.escrow_fee_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(ErrorCode::MissingFeeAccounts);
+        };
+        require_keys_eq!(fee_mint.key(), fee_mint_key, ErrorCode::InvalidFeeMint);
+        require_keys_eq!(
+            escrow_fee_account.key(),
+            get_associated_token_address_with_program_id(
+                &ctx.accounts.escrow.key(),
+                &fee_mint_key,
+                &token_program.key(),
+            ),
+            ErrorCode::MissingFeeAccounts
+        );
+
+        // Never created if no one paid in tokens
+        if !escrow_fee_account.data_is_empty() {
+            let balance = {
+                let data = escrow_fee_account.try_borrow_data()?;
+                TokenAccount::try_deserialize(&mut &data[..])?.amount
+            };
+            if balance > 0 {
+                let authority_fee_account = ctx
+                    .accounts
+                    .authority_fee_account
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingFeeAccounts)?;
+                let cpi_context = CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: escrow_fee_account.to_account_info(),
+                        mint: fee_mint.to_account_info(),
+                        to: authority_fee_account.to_account_info(),
+                        authority: ctx.accounts.escrow.to_account_info(),
+                    },
+                    escrow_signer,
+                );
+                transfer_checked(cpi_context, balance, fee_mint.decimals)?;
+            }
+
+            let cpi_context = CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: escrow_fee_account.to_account_info(),
+                    destination: ctx.accounts.authority.to_account_info(),
+                    authority: ctx.accounts.escrow.to_account_info(),
+                },
+                escrow_signer,
+            );
+            close_account(cpi_context)?;
+        }
+    }
+
+    // Leftover escrow lamports belong to whoever manages the contest now
+    let escrow_amount = ctx.accounts.escrow.lamports();
+    transfer_lamports_from_pda(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        escrow_signer,
+        escrow_amount,
+    )?;
+
+    // So does unspent sponsorship budget
+    let gas_pool_seeds: &[&[u8]] = &[
+        b"gas_pool",
+        contest_key.as_ref(),
+        &[ctx.bumps.gas_pool],
+    ];
+    let gas_pool_amount = ctx.accounts.gas_pool.lamports();
+    transfer_lamports_from_pda(
+        &ctx.accounts.gas_pool.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[gas_pool_seeds],
+        gas_pool_amount,
+    )?;
+
+    msg!(
+        "Contest closed: swept {} lamports from escrow and {} from gas pool",
+        escrow_amount,
+        gas_pool_amount
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseContest<'info> {
+    #[account(
+        mut,
+        close = creator,
+        has_one = creator @ ErrorCode::UnauthorizedCreator,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", contest.creator.as_ref(), &contest.contest_id.to_le_bytes()],
+        bump
+    )]
+    /// CHECK: Escrow PDA validated by seeds
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"gas_pool", contest.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Gas pool PDA validated by seeds
+    pub gas_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Rent recipient, validated against contest.creator
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow and gas pool recipient, validated against contest.authority
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Escrow's associated token account for the fee mint, checked in the
+    /// handler; may not exist if no token fee was ever paid
+    #[account(mut)]
+    pub escrow_fee_account: Option<UncheckedAccount<'info>>,
+
+    /// Receives stray tokens left in the escrow's fee account
+    #[account(
+        mut,
+        token::mint = fee_mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub authority_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Closes a milestone once its contest is settled, returning rent to the contest creator
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **close constraint** - Rent goes back to the creator who paid it, even after a handover
+/// 2. **Stored bump** - Seeds re-derived with the milestone's own index and bump
+pub fn close_milestone(ctx: Context<CloseMilestone>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.is_closable(clock.unix_timestamp),
+        ErrorCode::ContestNotClosable
+    );
+
+    contest.open_accounts = contest.open_accounts.saturating_sub(1);
+
+    msg!("Milestone {} closed", ctx.accounts.milestone.index);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseMilestone<'info> {
+    #[account(
+        mut,
+        has_one = creator @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        close = creator,
+        has_one = contest,
+        seeds = [b"milestone", contest.key().as_ref(), &[milestone.index]],
+        bump = milestone.bump
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    /// CHECK: Rent recipient, validated against contest.creator
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Closes a registration once its contest is settled, returning rent to the participant
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **close constraint** - Rent goes back to the participant who paid it
+/// 2. **Permissionless crank** - No signer needed; the recipient is fixed by has_one
+pub fn close_registration(ctx: Context<CloseRegistration>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.is_closable(clock.unix_timestamp),
+        ErrorCode::ContestNotClosable
+    );
+
+    contest.open_accounts = contest.open_accounts.saturating_sub(1);
+
+    msg!("Registration closed for participant {}", ctx.accounts.participant.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseRegistration<'info> {
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        close = participant,
+        has_one = participant @ ErrorCode::UnauthorizedParticipant,
+        seeds = [b"registration", contest.key().as_ref(), participant.key().as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+
+    /// CHECK: Rent recipient, validated against registration.participant
+    #[account(mut)]
+    pub participant: UncheckedAccount<'info>,
+}
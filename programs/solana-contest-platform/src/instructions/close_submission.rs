@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Closes a submission once its contest is settled, returning rent to whoever paid it
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **close constraint** - Anchor zeroes the account and moves its lamports to `participant`
+/// 2. **Permissionless crank** - Anyone can close; rent only goes to whoever paid it
+/// 3. **Dispute window** - Accounts stay readable for 7 days after settlement
+/// 4. **Direct lamport moves** - Rent the gas pool sponsored is moved back to the
+///    pool before close; the program owns the submission, so no CPI is needed
+pub fn close_submission(ctx: Context<CloseSubmission>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let submission = &ctx.accounts.submission;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.is_closable(clock.unix_timestamp),
+        ErrorCode::ContestNotClosable
+    );
+    // A cancelled contest owes the entry fee back before the record goes away
+    require!(
+        contest.status != ContestStatus::Cancelled
+            || submission.entry_fee_paid == 0
+            || submission.entry_fee_refunded,
+        ErrorCode::EntryFeeRefundPending
+    );
+
+    if submission.rent_sponsored > 0 {
+        let refund = submission.rent_sponsored.min(submission.get_lamports());
+        submission.sub_lamports(refund)?;
+        ctx.accounts.gas_pool.add_lamports(refund)?;
+    }
+
+    contest.open_accounts = contest.open_accounts.saturating_sub(1);
+
+    msg!("Submission closed for participant {}", submission.participant);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseSubmission<'info> {
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        close = participant,
+        has_one = participant @ ErrorCode::UnauthorizedParticipant,
+        seeds = [b"submission", contest.key().as_ref(), participant.key().as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    /// CHECK: Rent recipient, validated against submission.participant
+    #[account(mut)]
+    pub participant: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"gas_pool", contest.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Gas pool PDA - receives back the rent it sponsored
+    pub gas_pool: UncheckedAccount<'info>,
+}
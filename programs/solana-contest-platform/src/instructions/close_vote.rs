@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Closes a judge's vote once its contest is settled, returning rent to the judge
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **close constraint** - Rent goes back to the judge who paid it
+/// 2. **has_one constraint** - The vote is matched to its contest by the stored key;
+///    version 0 votes (see `migrate_contest`) keep their pre-track address, so the
+///    seeds cannot be re-derived uniformly
+pub fn close_vote(ctx: Context<CloseVote>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.is_closable(clock.unix_timestamp),
+        ErrorCode::ContestNotClosable
+    );
+
+    contest.open_accounts = contest.open_accounts.saturating_sub(1);
+
+    msg!("Vote closed for judge {}", ctx.accounts.judge.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseVote<'info> {
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        close = judge,
+        has_one = judge @ ErrorCode::UnauthorizedJudge,
+        has_one = contest,
+    )]
+    pub vote: Account<'info, JudgeVoteAccount>,
+
+    /// CHECK: Rent recipient, validated against vote.judge
+    #[account(mut)]
+    pub judge: UncheckedAccount<'info>,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Configures the entry fee charged by `submit_entry`
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **has_one constraint** - Only the contest authority can set the fee
+/// 2. **Rent Sysvar** - Lamport fees must keep the escrow rent-exempt
+/// 3. **Basis points** - Fee split expressed out of 10,000
+///
+/// A fee of 0 disables entry fees. `entry_fee_mint` of `None` charges
+/// lamports; `Some(mint)` charges that SPL token into the escrow's
+/// associated token account.
+pub fn configure_entry_fee(
+    ctx: Context<ConfigureEntryFee>,
+    entry_fee: u64,
+    entry_fee_mint: Option<Pubkey>,
+    prize_share_bps: u16,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    require!(
+        contest.status == ContestStatus::Setup,
+        ErrorCode::InvalidContestState
+    );
+    require!(prize_share_bps <= 10_000, ErrorCode::InvalidFeeSplit);
+    require!(
+        prize_share_bps == 0 || contest.tracks.is_empty(),
+        ErrorCode::FeeShareWithTracks
+    );
+
+    // Every refund leaves either nothing or at least one fee in the escrow,
+    // so a fee at or above the rent-exempt minimum keeps it valid throughout
+    if entry_fee > 0 && entry_fee_mint.is_none() {
+        let rent_minimum = Rent::get()?.minimum_balance(0);
+        require!(entry_fee >= rent_minimum, ErrorCode::EntryFeeTooLow);
+    }
+
+    contest.entry_fee = entry_fee;
+    contest.entry_fee_mint = entry_fee_mint;
+    contest.entry_fee_prize_bps = prize_share_bps;
+
+    msg!(
+        "Entry fee configured: {} ({} bps to prize)",
+        entry_fee,
+        prize_share_bps
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureEntryFee<'info> {
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    pub authority: Signer<'info>,
+}
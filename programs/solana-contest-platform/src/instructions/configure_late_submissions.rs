@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Configures a grace window for late entries and their judging penalty
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **has_one constraint** - Only the contest authority can change submission rules
+/// 2. **Relative windows** - The grace window is a duration after the deadline
+///
+/// Late entries need `late_penalty_votes` more judge approvals than
+/// on-time entries to win (capped at the number of judges), so they lose
+/// any tie with an on-time entry. A `late_period` of 0 disables late entries.
+pub fn configure_late_submissions(
+    ctx: Context<ConfigureLateSubmissions>,
+    late_period: i64,
+    late_penalty_votes: u8,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    require!(
+        contest.status == ContestStatus::Setup,
+        ErrorCode::InvalidContestState
+    );
+    require!(late_period >= 0, ErrorCode::InvalidLatePeriod);
+
+    contest.late_period = late_period;
+    contest.late_penalty_votes = late_penalty_votes;
+
+    msg!(
+        "Late submissions configured: {}s window, {} penalty votes",
+        late_period,
+        late_penalty_votes
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureLateSubmissions<'info> {
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    pub authority: Signer<'info>,
+}
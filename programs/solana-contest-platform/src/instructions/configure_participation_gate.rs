@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Restricts participation to token holders or NFT collection members
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **has_one constraint** - Only the contest authority can set the gate
+/// 2. **Enum arguments** - Gate kind and parameters passed as one Borsh enum
+///
+/// Passing `None` removes the gate.
+pub fn configure_participation_gate(
+    ctx: Context<ConfigureParticipationGate>,
+    gate: Option<ParticipationGate>,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    require!(
+        contest.status == ContestStatus::Setup,
+        ErrorCode::InvalidContestState
+    );
+    if let Some(ParticipationGate::TokenBalance { min_amount, .. }) = &gate {
+        require!(*min_amount > 0, ErrorCode::InvalidGate);
+    }
+
+    contest.participation_gate = gate;
+
+    msg!("Participation gate configured for contest {}", contest.contest_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureParticipationGate<'info> {
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    pub authority: Signer<'info>,
+}
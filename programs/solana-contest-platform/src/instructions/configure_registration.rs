@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Configures the optional registration window and participant cap
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **has_one constraint** - Only the contest authority can change contest rules
+/// 2. **Setup-only configuration** - Rules are frozen once the contest is funded
+///
+/// A `registration_deadline` of 0 keeps open entry; a `max_participants`
+/// of 0 removes the cap.
+pub fn configure_registration(
+    ctx: Context<ConfigureRegistration>,
+    registration_deadline: i64,
+    max_participants: u32,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.status == ContestStatus::Setup,
+        ErrorCode::InvalidContestState
+    );
+    if registration_deadline != 0 {
+        require!(
+            registration_deadline > clock.unix_timestamp
+                && registration_deadline < contest.submission_deadline,
+            ErrorCode::InvalidRegistrationDeadline
+        );
+    }
+
+    contest.registration_deadline = registration_deadline;
+    contest.max_participants = max_participants;
+
+    msg!(
+        "Registration configured: deadline {}, max participants {}",
+        registration_deadline,
+        max_participants
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureRegistration<'info> {
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Enables sealed (commit-reveal) submissions
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **has_one constraint** - Only the contest authority can change submission mode
+/// 2. **Relative windows** - The reveal window is stored as a duration after
+///    the deadline, so it moves with the deadline
+///
+/// A `reveal_period` of 0 restores open submissions.
+pub fn configure_sealed_submissions(
+    ctx: Context<ConfigureSealedSubmissions>,
+    reveal_period: i64,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    require!(
+        contest.status == ContestStatus::Setup,
+        ErrorCode::InvalidContestState
+    );
+    require!(reveal_period >= 0, ErrorCode::InvalidRevealPeriod);
+
+    contest.reveal_period = reveal_period;
+
+    msg!("Sealed submissions configured: reveal period {}s", reveal_period);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureSealedSubmissions<'info> {
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    pub authority: Signer<'info>,
+}
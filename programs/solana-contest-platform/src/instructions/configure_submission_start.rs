@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Schedules when the contest starts accepting submissions
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **has_one constraint** - Only the contest authority can change contest rules
+/// 2. **Setup-only configuration** - The start is fixed once the contest is funded,
+///    so an announced contest can be funded in advance without opening early
+///
+/// A `submission_start` of 0 opens submissions as soon as the contest is funded.
+pub fn configure_submission_start(
+    ctx: Context<ConfigureSubmissionStart>,
+    submission_start: i64,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.status == ContestStatus::Setup,
+        ErrorCode::InvalidContestState
+    );
+    if submission_start != 0 {
+        require!(
+            submission_start > clock.unix_timestamp
+                && submission_start < contest.submission_deadline,
+            ErrorCode::InvalidSubmissionStart
+        );
+    }
+
+    contest.submission_start = submission_start;
+
+    msg!("Submissions open at {}", contest.submissions_open_at());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureSubmissionStart<'info> {
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    pub authority: Signer<'info>,
+}
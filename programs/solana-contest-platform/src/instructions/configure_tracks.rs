@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Splits the contest into named tracks, each with its own prize
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **has_one constraint** - Only the contest authority can define tracks
+/// 2. **Vec of structs in an account** - Tracks live inside the Contest account
+/// 3. **Checked arithmetic** - Track prizes are summed without overflow
+///
+/// The contest's `prize_amount` becomes the sum of all track prizes, so
+/// `fund_contest` escrows every track at once.
+pub fn configure_tracks(
+    ctx: Context<ConfigureTracks>,
+    tracks: Vec<TrackConfig>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let contest = &mut ctx.accounts.contest;
+
+    require!(
+        contest.status == ContestStatus::Setup,
+        ErrorCode::InvalidContestState
+    );
+    require!(
+        !tracks.is_empty() && tracks.len() <= MAX_TRACKS,
+        ErrorCode::InvalidTrackCount
+    );
+    require!(!contest.has_milestones(), ErrorCode::MilestonesWithTracks);
+    // Fee prize shares are paid to a single winner, which tracks do not have
+    require!(
+        contest.entry_fee_prize_bps == 0,
+        ErrorCode::FeeShareWithTracks
+    );
+
+    let mut total_prize: u64 = 0;
+    for track in tracks.iter() {
+        require!(track.name.len() <= 32, ErrorCode::TrackNameTooLong);
+        config.check_prize(track.prize_amount)?;
+        total_prize = total_prize
+            .checked_add(track.prize_amount)
+            .ok_or(ErrorCode::CalculationOverflow)?;
+    }
+
+    contest.tracks = tracks
+        .into_iter()
+        .map(|t| Track {
+            name: t.name,
+            prize_amount: t.prize_amount,
+            winner: None,
+        })
+        .collect();
+    contest.prize_amount = total_prize;
+
+    msg!(
+        "Configured {} tracks, total prize {} lamports",
+        contest.tracks.len(),
+        total_prize
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureTracks<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    pub authority: Signer<'info>,
+}
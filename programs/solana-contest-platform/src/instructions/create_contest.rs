@@ -6,70 +6,129 @@ use crate::errors::ErrorCode;
 ///
 /// # Anchor Concepts Demonstrated:
 /// 1. **PDA Derivation with Seeds** - Contest account is a PDA derived from creator + contest_id
+///    (the ID comes from the global registry counter)
 /// 2. **Account Initialization with `init` constraint** - Automatically creates and initializes account
 /// 3. **Space Calculation with `InitSpace`** - Automatically calculates account size
 /// 4. **Input Validation with `require!` macro** - Validates all inputs before processing
 /// 5. **Clock Sysvar** - Access blockchain time for deadline validation
+/// 6. **Config PDA** - Prize and judge limits come from protocol config
+/// 7. **init_if_needed** - Creator index and its current page are created on first use
+#[allow(clippy::too_many_arguments)]
 pub fn create_contest(
     ctx: Context<CreateContest>,
-    contest_id: u64,
     title: String,
     description: String,
     prize_amount: u64, // Prize amount in lamports (1 SOL = 1_000_000_000 lamports)
     submission_deadline: i64,
     judges: Vec<Pubkey>,
     approval_threshold: u8,
+    allowlist_root: Option<[u8; 32]>,
 ) -> Result<()> {
     let contest = &mut ctx.accounts.contest;
     let clock = Clock::get()?;
+    let contest_id = ctx.accounts.registry.contest_count;
 
-    // Validate input parameters using require! macro
-    require!(title.len() <= 100, ErrorCode::TitleTooLong);
-    require!(description.len() <= 500, ErrorCode::DescriptionTooLong);
-    require!(judges.len() <= 5, ErrorCode::TooManyJudges);
-    require!(judges.len() > 0, ErrorCode::NoJudges);
-    require!(
-        approval_threshold as usize <= judges.len(),
-        ErrorCode::InvalidThreshold
-    );
-    require!(prize_amount >= 10_000_000, ErrorCode::PrizeTooLow); // Minimum 0.01 SOL
+    let config = &ctx.accounts.config;
+    validate_contest_params(config, &title, &description, prize_amount, &judges, approval_threshold)?;
     require!(
         submission_deadline > clock.unix_timestamp,
         ErrorCode::InvalidDeadline
     );
 
     // Initialize contest account fields
-    contest.creator = ctx.accounts.creator.key();
-    contest.contest_id = contest_id;
+    let creator = ctx.accounts.creator.key();
+    contest.initialize(creator, contest_id, ctx.bumps.contest, clock.unix_timestamp);
     contest.title = title;
     contest.description = description;
     contest.prize_amount = prize_amount;
     contest.submission_deadline = submission_deadline;
     contest.judges = judges;
     contest.approval_threshold = approval_threshold;
-    contest.status = ContestStatus::Setup;
-    contest.submission_count = 0;
-    contest.created_at = clock.unix_timestamp;
-    contest.gas_sponsorship_enabled = false;
-    contest.funded = false;
-    contest.bump = ctx.bumps.contest;
+    contest.allowlist_root = allowlist_root;
+    contest.reclaim_delay = config.reclaim_delay;
+    contest.platform_fee_bps = config.platform_fee_bps;
+
+    // Advance the global counter and record the ID in the creator's index
+    ctx.accounts.registry.advance()?;
+    ctx.accounts.creator_index.record(
+        &mut ctx.accounts.index_page,
+        creator,
+        contest_id,
+        ctx.bumps.creator_index,
+        ctx.bumps.index_page,
+    )?;
 
     msg!("Contest created with ID: {}", contest_id);
     Ok(())
 }
 
+/// Input checks shared by `create_contest` and series templates
+pub(crate) fn validate_contest_params(
+    config: &Config,
+    title: &str,
+    description: &str,
+    prize_amount: u64,
+    judges: &[Pubkey],
+    approval_threshold: u8,
+) -> Result<()> {
+    // Validate input parameters using require! macro
+    require!(title.len() <= 100, ErrorCode::TitleTooLong);
+    require!(description.len() <= 500, ErrorCode::DescriptionTooLong);
+    config.check_judges(judges)?;
+    require!(
+        approval_threshold > 0 && approval_threshold as usize <= judges.len(),
+        ErrorCode::InvalidThreshold
+    );
+    config.check_prize(prize_amount)?;
+    Ok(())
+}
+
 #[derive(Accounts)]
-#[instruction(contest_id: u64)]
 pub struct CreateContest<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, Registry>,
+
     #[account(
         init,
         payer = creator,
         space = 8 + Contest::INIT_SPACE,
-        seeds = [b"contest", creator.key().as_ref(), &contest_id.to_le_bytes()],
+        seeds = [b"contest", creator.key().as_ref(), &registry.contest_count.to_le_bytes()],
         bump
     )]
     pub contest: Account<'info, Contest>,
 
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorIndex::INIT_SPACE,
+        seeds = [b"creator_index", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_index: Account<'info, CreatorIndex>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorIndexPage::INIT_SPACE,
+        seeds = [
+            b"creator_index",
+            creator.key().as_ref(),
+            &creator_index.next_page().to_le_bytes()
+        ],
+        bump
+    )]
+    pub index_page: Account<'info, CreatorIndexPage>,
+
     #[account(mut)]
     pub creator: Signer<'info>,
 
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::instructions::create_contest::validate_contest_params;
+
+/// Creates a recurring contest series and deposits its first budget
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Template account** - Round parameters are stored once and copied into each round
+/// 2. **Vault PDA** - Budget sits in a system-owned PDA the program can sign for
+/// 3. **Struct instruction arguments** - The template is passed as one borsh struct
+pub fn create_series(
+    ctx: Context<CreateSeries>,
+    series_id: u64,
+    template: SeriesTemplate,
+    initial_budget: u64,
+) -> Result<()> {
+    validate_contest_params(
+        &ctx.accounts.config,
+        &template.title,
+        &template.description,
+        template.prize_amount,
+        &template.judges,
+        template.approval_threshold,
+    )?;
+    require!(
+        template.round_duration > 0 && template.round_interval > 0,
+        ErrorCode::InvalidSeriesSchedule
+    );
+
+    let series = &mut ctx.accounts.series;
+    series.creator = ctx.accounts.creator.key();
+    series.series_id = series_id;
+    series.title = template.title;
+    series.description = template.description;
+    series.prize_amount = template.prize_amount;
+    series.judges = template.judges;
+    series.approval_threshold = template.approval_threshold;
+    series.round_duration = template.round_duration;
+    series.round_interval = template.round_interval;
+    series.next_round_at = template.first_round_at;
+    series.max_rounds = template.max_rounds;
+    series.rounds_opened = 0;
+    series.current_contest = None;
+    series.bump = ctx.bumps.series;
+    series.vault_bump = ctx.bumps.vault;
+
+    if initial_budget > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        );
+        transfer(cpi_context, initial_budget)?;
+    }
+
+    msg!(
+        "Series {} created; first round at {}, budget {} lamports",
+        series_id,
+        series.next_round_at,
+        initial_budget
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(series_id: u64)]
+pub struct CreateSeries<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Series::INIT_SPACE,
+        seeds = [b"series", creator.key().as_ref(), &series_id.to_le_bytes()],
+        bump
+    )]
+    pub series: Account<'info, Series>,
+
+    #[account(
+        mut,
+        seeds = [b"series_vault", series.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Series vault PDA validated by seeds
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
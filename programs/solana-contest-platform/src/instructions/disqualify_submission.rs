@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::SubmissionDisqualified;
+use crate::access::has_permission;
+
+/// Judge vote (or operator ruling) to disqualify a submission
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Bitmask voting** - Each judge's index is one bit, so repeat votes are no-ops
+/// 2. **has_one constraint** - Submission must belong to this contest
+/// 3. **Events** - emit! records the disqualification once quorum is reached
+/// 4. **Delegated permission** - The authority or a DISQUALIFY operator can remove
+///    spam immediately while submissions are still open. Once the deadline passes,
+///    the judge quorum is the only way to disqualify; an operator who is also a
+///    judge just casts that judge's vote
+///
+/// Every judge must cite the same `reason_hash` (e.g. sha256 of a written
+/// ruling). The submission is disqualified once `approval_threshold` judges
+/// agree.
+pub fn disqualify_submission(
+    ctx: Context<DisqualifySubmission>,
+    reason_hash: [u8; 32],
+) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let submission = &mut ctx.accounts.submission;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.status == ContestStatus::Active,
+        ErrorCode::InvalidContestState
+    );
+    require!(!submission.disqualified, ErrorCode::AlreadyDisqualified);
+
+    let signer = ctx.accounts.signer.key();
+    let operator = has_permission(
+        contest,
+        &signer,
+        ctx.accounts.role.as_deref(),
+        ContestRole::DISQUALIFY,
+    );
+    if operator && clock.unix_timestamp < contest.submission_deadline {
+        submission.disqualify_reason = reason_hash;
+        submission.disqualified = true;
+
+        emit!(SubmissionDisqualified {
+            contest: contest.key(),
+            participant: submission.participant,
+            reason_hash,
+            approvals: 0,
+            disqualified_at: clock.unix_timestamp,
+        });
+        msg!("Submission by {} disqualified by operator {}", submission.participant, signer);
+        return Ok(());
+    }
+
+    // Runtime check: Verify judge is in authorized judges list
+    let judge_index = contest
+        .judges
+        .iter()
+        .position(|j| *j == signer)
+        .ok_or(ErrorCode::UnauthorizedJudge)?;
+
+    // The first vote sets the reason; later votes must agree with it
+    if submission.disqualify_votes == 0 {
+        submission.disqualify_reason = reason_hash;
+    } else {
+        require!(
+            submission.disqualify_reason == reason_hash,
+            ErrorCode::DisqualificationReasonMismatch
+        );
+    }
+    submission.disqualify_votes |= 1 << judge_index;
+
+    let approvals = submission.disqualify_votes.count_ones() as u8;
+    if approvals >= contest.approval_threshold {
+        submission.disqualified = true;
+
+        emit!(SubmissionDisqualified {
+            contest: contest.key(),
+            participant: submission.participant,
+            reason_hash,
+            approvals,
+            disqualified_at: clock.unix_timestamp,
+        });
+        msg!("Submission by {} disqualified", submission.participant);
+    } else {
+        msg!(
+            "Disqualification vote recorded ({}/{})",
+            approvals,
+            contest.approval_threshold
+        );
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DisqualifySubmission<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        has_one = contest,
+    )]
+    pub submission: Account<'info, Submission>,
+
+    /// Judge, contest authority, or operator with the DISQUALIFY role
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", contest.key().as_ref(), signer.key().as_ref()],
+        bump = role.bump
+    )]
+    pub role: Option<Account<'info, ContestRole>>,
+}
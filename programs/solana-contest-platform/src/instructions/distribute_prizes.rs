@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::ErrorCode;
+use crate::utils::transfer_lamports_from_pda;
 
 /// Distributes prizes when consensus is reached
 ///
@@ -8,10 +9,19 @@ use crate::errors::ErrorCode;
 /// 1. **PDA Signing** - Escrow PDA signs the transfer without a private key
 /// 2. **remaining_accounts** - Dynamic number of vote accounts passed in
 /// 3. **Vote Counting Logic** - Iterates through vote PDAs to reach consensus
-/// 4. **Lamport Manipulation** - Direct SOL transfer using try_borrow_mut_lamports
+/// 4. **Signed System Transfer** - Escrow is system-owned, so the System Program moves the SOL
 /// 5. **Account Deserialization in Loop** - Safely deserializes vote accounts
-pub fn distribute_prizes<'info>(ctx: Context<'_, '_, 'info, 'info, DistributePrizes<'info>>) -> Result<()> {
+/// 6. **Platform fee** - The contest's snapshotted fee rate goes to the treasury PDA
+///
+/// Track contests call this once per track; the contest completes when
+/// every track has a winner. Milestone contests only record the winner here;
+/// `approve_milestone` releases the prize piece by piece.
+pub fn distribute_prizes<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributePrizes<'info>>,
+    track: u8,
+) -> Result<()> {
     let contest = &ctx.accounts.contest;
+    let clock = Clock::get()?;
 
     // Validate contest state
     require!(contest.funded, ErrorCode::ContestNotFunded);
@@ -20,43 +30,119 @@ pub fn distribute_prizes<'info>(ctx: Context<'_, '_, 'info, 'info, DistributePri
         ErrorCode::InvalidContestState
     );
 
-    // Count votes for the specified winner using remaining_accounts
-    let mut winner_votes = 0;
+    let track_index = track as usize;
+    require!(track_index < contest.track_count(), ErrorCode::InvalidTrack);
+    if let Some(t) = contest.tracks.get(track_index) {
+        require!(t.winner.is_none(), ErrorCode::TrackAlreadyDistributed);
+    }
+    require!(
+        ctx.accounts.winner_submission.track == track,
+        ErrorCode::WrongTrack
+    );
+
+    // Only entries that were revealed (always true for open contests) can win
+    require!(
+        ctx.accounts.winner_submission.revealed,
+        ErrorCode::SubmissionNotRevealed
+    );
+
+    require!(
+        !ctx.accounts.winner_submission.disqualified,
+        ErrorCode::SubmissionDisqualified
+    );
+
+    // Count votes for the specified winner using remaining_accounts.
+    // Each current judge counts once, even if their vote is passed twice.
+    let mut counted_judges: Vec<Pubkey> = Vec::with_capacity(contest.judges.len());
     for account in ctx.remaining_accounts.iter() {
         // Try to deserialize as JudgeVoteAccount
         match Account::<JudgeVoteAccount>::try_from(account) {
             Ok(vote_data) => {
-                // Check if vote is for this contest and this winner
+                // Check if vote is for this contest, track and winner
                 if vote_data.contest == contest.key() &&
-                   vote_data.winner == ctx.accounts.winner.key() {
-                    winner_votes += 1;
+                   vote_data.track == track &&
+                   vote_data.winner == ctx.accounts.winner.key() &&
+                   contest.judges.contains(&vote_data.judge) &&
+                   !counted_judges.contains(&vote_data.judge) {
+                    counted_judges.push(vote_data.judge);
                 }
             }
             Err(_) => continue,
         }
     }
+    let winner_votes = counted_judges.len();
 
-    // Verify consensus threshold is reached
+    // Verify consensus threshold is reached; late entries carry a vote penalty
     require!(
-        winner_votes >= contest.approval_threshold as usize,
+        winner_votes >= contest.required_votes(ctx.accounts.winner_submission.lateness),
         ErrorCode::ConsensusNotReached
     );
 
-    let distribution_amount = contest.prize_amount;
+    // Milestone bounties lock in the winner; payouts follow per milestone
+    if contest.has_milestones() {
+        require!(contest.winner.is_none(), ErrorCode::WinnerAlreadySelected);
+        let contest = &mut ctx.accounts.contest;
+        contest.winner = Some(ctx.accounts.winner.key());
+
+        msg!(
+            "Winner selected: {} ({} milestones pending)",
+            ctx.accounts.winner.key(),
+            contest.milestone_count
+        );
+        return Ok(());
+    }
+
+    let distribution_amount = match contest.tracks.get(track_index) {
+        Some(t) => t.prize_amount,
+        None => contest.prize_amount,
+    };
 
-    // Transfer lamports from escrow to winner
-    **ctx.accounts.escrow.to_account_info().try_borrow_mut_lamports()? -= distribution_amount;
-    **ctx.accounts.winner.to_account_info().try_borrow_mut_lamports()? += distribution_amount;
+    // Withhold the platform fee rate fixed when the contest was created
+    let platform_fee = contest.platform_fee(distribution_amount);
+    let winner_amount = distribution_amount - platform_fee;
 
-    // Update contest status to Completed
+    // Transfer lamports from escrow to winner and treasury, signed by the escrow PDA
+    let contest_id = contest.contest_id.to_le_bytes();
+    let escrow_seeds: &[&[u8]] = &[
+        b"escrow",
+        contest.creator.as_ref(),
+        &contest_id,
+        &[ctx.bumps.escrow],
+    ];
+    transfer_lamports_from_pda(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.winner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[escrow_seeds],
+        winner_amount,
+    )?;
+    transfer_lamports_from_pda(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[escrow_seeds],
+        platform_fee,
+    )?;
+
+    // Record the winner; the contest completes once every track is decided
     let contest = &mut ctx.accounts.contest;
-    contest.status = ContestStatus::Completed;
+    let winner = ctx.accounts.winner.key();
+    if contest.tracks.is_empty() {
+        contest.winner = Some(winner);
+        contest.settle(ContestStatus::Completed, clock.unix_timestamp);
+    } else {
+        contest.tracks[track_index].winner = Some(winner);
+        if contest.tracks.iter().all(|t| t.winner.is_some()) {
+            contest.settle(ContestStatus::Completed, clock.unix_timestamp);
+        }
+    }
 
     msg!(
-        "Prize distributed: {} lamports ({} SOL) to winner: {}",
-        distribution_amount,
-        distribution_amount as f64 / 1_000_000_000.0,
-        ctx.accounts.winner.key()
+        "Prize distributed: {} lamports ({} SOL) to winner: {}, platform fee {}",
+        winner_amount,
+        winner_amount as f64 / 1_000_000_000.0,
+        ctx.accounts.winner.key(),
+        platform_fee
     );
 
     Ok(())
@@ -64,13 +150,19 @@ pub fn distribute_prizes<'info>(ctx: Context<'_, '_, 'info, 'info, DistributePri
 
 #[derive(Accounts)]
 pub struct DistributePrizes<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(mut)]
     pub contest: Account<'info, Contest>,
 
     #[account(
         mut,
         seeds = [b"escrow", contest.creator.as_ref(), &contest.contest_id.to_le_bytes()],
-        bump = contest.bump
+        bump
     )]
     /// CHECK: Escrow PDA validated by seeds
     pub escrow: AccountInfo<'info>,
@@ -79,5 +171,19 @@ pub struct DistributePrizes<'info> {
     #[account(mut)]
     pub winner: AccountInfo<'info>,
 
+    #[account(
+        seeds = [b"submission", contest.key().as_ref(), winner.key().as_ref()],
+        bump = winner_submission.bump
+    )]
+    pub winner_submission: Account<'info, Submission>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    /// CHECK: Treasury PDA validated by seeds
+    pub treasury: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
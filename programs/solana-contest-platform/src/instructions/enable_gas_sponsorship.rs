@@ -9,6 +9,7 @@ use crate::errors::ErrorCode;
 /// 1. **Multiple PDA pattern** - Gas pool is separate PDA derived from contest
 /// 2. **Optional features** - Gas sponsorship is an optional enhancement
 /// 3. **State transitions** - Contest must be Active before enabling sponsorship
+/// 4. **Delegated permission** - The authority or a GAS_SPONSORSHIP operator can fund the pool
 pub fn enable_gas_sponsorship(
     ctx: Context<EnableGasSponsorship>,
     gas_budget: u64,
@@ -24,7 +25,7 @@ pub fn enable_gas_sponsorship(
     let cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
         Transfer {
-            from: ctx.accounts.creator.to_account_info(),
+            from: ctx.accounts.operator.to_account_info(),
             to: ctx.accounts.gas_pool.to_account_info(),
         },
     );
@@ -38,10 +39,7 @@ pub fn enable_gas_sponsorship(
 
 #[derive(Accounts)]
 pub struct EnableGasSponsorship<'info> {
-    #[account(
-        mut,
-        has_one = creator @ ErrorCode::UnauthorizedCreator,
-    )]
+    #[account(mut)]
     pub contest: Account<'info, Contest>,
 
     #[account(
@@ -53,7 +51,13 @@ pub struct EnableGasSponsorship<'info> {
     pub gas_pool: AccountInfo<'info>,
 
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", contest.key().as_ref(), operator.key().as_ref()],
+        bump = role.bump
+    )]
+    pub role: Option<Account<'info, ContestRole>>,
 
     pub system_program: Program<'info, System>,
 }
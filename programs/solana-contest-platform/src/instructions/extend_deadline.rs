@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::DeadlineExtended;
+
+/// Moves the submission deadline later
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Delegated permission** - The authority or an EXTEND_DEADLINE operator can extend
+/// 2. **Monotonic updates** - Deadlines can only move later, within a cumulative cap
+/// 3. **Derived time-locks** - Late, reveal and reclaim windows are measured
+///    from the deadline, so they all shift by the same amount
+pub fn extend_deadline(
+    ctx: Context<ExtendDeadline>,
+    new_deadline: i64,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.status == ContestStatus::Setup || contest.status == ContestStatus::Active,
+        ErrorCode::InvalidContestState
+    );
+    require!(
+        clock.unix_timestamp < contest.submission_deadline,
+        ErrorCode::SubmissionDeadlinePassed
+    );
+    require!(
+        new_deadline > contest.submission_deadline,
+        ErrorCode::DeadlineNotLater
+    );
+
+    let old_deadline = contest.submission_deadline;
+    let total_extension = contest
+        .deadline_extension
+        .checked_add(new_deadline - old_deadline)
+        .ok_or(ErrorCode::CalculationOverflow)?;
+    require!(
+        total_extension <= MAX_DEADLINE_EXTENSION,
+        ErrorCode::ExtensionLimitExceeded
+    );
+
+    contest.submission_deadline = new_deadline;
+    contest.deadline_extension = total_extension;
+
+    emit!(DeadlineExtended {
+        contest: contest.key(),
+        old_deadline,
+        new_deadline,
+        total_extension,
+        reclaim_available_at: contest.reclaim_available_at(),
+    });
+
+    msg!(
+        "Deadline extended from {} to {} (total extension {}s)",
+        old_deadline,
+        new_deadline,
+        total_extension
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExtendDeadline<'info> {
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", contest.key().as_ref(), operator.key().as_ref()],
+        bump = role.bump
+    )]
+    pub role: Option<Account<'info, ContestRole>>,
+}
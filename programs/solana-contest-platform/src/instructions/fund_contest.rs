@@ -9,7 +9,7 @@ use crate::errors::ErrorCode;
 /// 1. **Cross-Program Invocation (CPI)** - Calling System Program's transfer instruction
 /// 2. **CpiContext** - Wrapper for CPIs that handles account info conversion
 /// 3. **State Validation** - Checking contest status before allowing operations
-/// 4. **has_one constraint** - Validates creator field matches signer
+/// 4. **has_one constraint** - Validates authority field matches signer
 /// 5. **PDA as receiving account** - Escrow PDA receives funds without private key
 pub fn fund_contest(ctx: Context<FundContest>) -> Result<()> {
     let contest = &mut ctx.accounts.contest;
@@ -20,14 +20,18 @@ pub fn fund_contest(ctx: Context<FundContest>) -> Result<()> {
         ErrorCode::InvalidContestState
     );
     require!(!contest.funded, ErrorCode::AlreadyFunded);
+    require!(
+        !contest.has_milestones() || contest.milestone_total == contest.prize_amount,
+        ErrorCode::MilestoneTotalMismatch
+    );
 
     let prize_amount = contest.prize_amount;
 
-    // Transfer SOL from creator to escrow using Cross-Program Invocation (CPI)
+    // Transfer SOL from authority to escrow using Cross-Program Invocation (CPI)
     let cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
         Transfer {
-            from: ctx.accounts.creator.to_account_info(),
+            from: ctx.accounts.authority.to_account_info(),
             to: ctx.accounts.escrow.to_account_info(),
         },
     );
@@ -50,20 +54,20 @@ pub fn fund_contest(ctx: Context<FundContest>) -> Result<()> {
 pub struct FundContest<'info> {
     #[account(
         mut,
-        has_one = creator @ ErrorCode::UnauthorizedCreator,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
     )]
     pub contest: Account<'info, Contest>,
 
     #[account(
         mut,
-        seeds = [b"escrow", creator.key().as_ref(), &contest.contest_id.to_le_bytes()],
+        seeds = [b"escrow", contest.creator.as_ref(), &contest.contest_id.to_le_bytes()],
         bump
     )]
     /// CHECK: This is safe because we only transfer SOL to this PDA
     pub escrow: AccountInfo<'info>,
 
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+
+/// Tops up a series budget
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Open funding** - Anyone (e.g. a sponsor) may add to the vault
+/// 2. **CPI** - System Program transfer into a PDA
+pub fn fund_series(ctx: Context<FundSeries>, amount: u64) -> Result<()> {
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.funder.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+        },
+    );
+    transfer(cpi_context, amount)?;
+
+    msg!("Series budget topped up by {} lamports", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundSeries<'info> {
+    pub series: Account<'info, Series>,
+
+    #[account(
+        mut,
+        seeds = [b"series_vault", series.key().as_ref()],
+        bump = series.vault_bump
+    )]
+    /// CHECK: Series vault PDA validated by seeds
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::RoleUpdated;
+
+/// Grants (or replaces) an operator's permissions on a contest
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **init_if_needed** - The first grant creates the role PDA; later grants overwrite it
+/// 2. **Permission bitmask** - One byte holds every delegated capability
+/// 3. **has_one constraint** - Only the contest authority can delegate
+pub fn grant_role(ctx: Context<GrantRole>, delegate: Pubkey, permissions: u8) -> Result<()> {
+    require!(
+        permissions != 0 && permissions & !ContestRole::ALL == 0,
+        ErrorCode::InvalidPermissions
+    );
+
+    let role = &mut ctx.accounts.role;
+    let is_new = role.contest == Pubkey::default();
+    role.contest = ctx.accounts.contest.key();
+    role.delegate = delegate;
+    role.permissions = permissions;
+    role.granted_at = Clock::get()?.unix_timestamp;
+    role.bump = ctx.bumps.role;
+
+    // Roles are child accounts, so close_contest waits for them to be revoked
+    if is_new {
+        let contest = &mut ctx.accounts.contest;
+        contest.open_accounts = contest.open_accounts.saturating_add(1);
+    }
+
+    emit!(RoleUpdated {
+        contest: ctx.accounts.contest.key(),
+        delegate,
+        permissions,
+    });
+
+    msg!("Granted permissions {:#04x} to {}", permissions, delegate);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ContestRole::INIT_SPACE,
+        seeds = [b"role", contest.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub role: Account<'info, ContestRole>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::program::Solarena;
+
+/// Creates the protocol config and names its first admin
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Singleton PDA** - Constant seeds mean only one config can exist
+/// 2. **Upgrade authority gate** - Only the program's upgrade authority, read
+///    from its ProgramData account, can initialize, so nobody can front-run it
+/// 3. **Rent-exempt treasury** - The treasury PDA is seeded with its rent-exempt
+///    minimum so small platform fees can always be credited to it
+pub fn initialize_config(
+    ctx: Context<InitializeConfig>,
+    admin: Pubkey,
+    params: ConfigParams,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = admin;
+    config.pending_admin = None;
+    config.paused = false;
+    config.bump = ctx.bumps.config;
+    config.apply(&params)?;
+
+    let treasury = &ctx.accounts.treasury;
+    let rent_minimum = Rent::get()?.minimum_balance(0);
+    let top_up = rent_minimum.saturating_sub(treasury.lamports());
+    if top_up > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: treasury.to_account_info(),
+            },
+        );
+        transfer(cpi_context, top_up)?;
+    }
+
+    msg!("Config initialized with admin {}", admin);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    /// CHECK: Treasury PDA validated by seeds
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::NotUpgradeAuthority
+    )]
+    pub program: Program<'info, Solarena>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
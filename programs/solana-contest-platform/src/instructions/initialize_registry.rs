@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Creates the global contest registry (once per deployment)
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Singleton PDA** - Constant seeds mean only one registry can ever exist
+/// 2. **Permissionless setup** - Whoever calls first pays the rent; `init` rejects repeats
+pub fn initialize_registry(ctx: Context<InitializeRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    registry.contest_count = 0;
+    registry.bump = ctx.bumps.registry;
+
+    msg!("Contest registry initialized");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeRegistry<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Registry::INIT_SPACE,
+        seeds = [b"registry"],
+        bump
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -7,10 +7,13 @@ use crate::errors::ErrorCode;
 /// # Anchor Concepts Demonstrated:
 /// 1. **Multisig Pattern with PDAs** - Each judge creates independent vote PDA
 /// 2. **Runtime Authorization Check** - Validates judge is in authorized list
-/// 3. **One vote per judge** - PDA seeds [contest, judge] enforce uniqueness
+/// 3. **One vote per judge per track** - PDA seeds [contest, judge, track] enforce uniqueness;
+///    `legacy_vote` must be empty so a judge whose version 0 vote was migrated
+///    cannot vote on track 0 again at the new address
 pub fn judge_vote(
     ctx: Context<JudgeVote>,
     winner: Pubkey,
+    track: u8,
 ) -> Result<()> {
     let contest = &ctx.accounts.contest;
     let vote = &mut ctx.accounts.vote;
@@ -25,6 +28,16 @@ pub fn judge_vote(
         clock.unix_timestamp >= contest.submission_deadline,
         ErrorCode::SubmissionPeriodNotEnded
     );
+    // Judging waits for late entries and, in sealed contests, for reveals
+    require!(
+        clock.unix_timestamp >= contest.reveal_deadline(),
+        ErrorCode::RevealPeriodNotEnded
+    );
+
+    require!(
+        (track as usize) < contest.track_count(),
+        ErrorCode::InvalidTrack
+    );
 
     // Runtime check: Verify judge is in authorized judges list
     require!(
@@ -32,19 +45,37 @@ pub fn judge_vote(
         ErrorCode::UnauthorizedJudge
     );
 
+    // Version 0 votes count as track 0 votes
+    require!(
+        track != 0 || ctx.accounts.legacy_vote.data_is_empty(),
+        ErrorCode::AlreadyVoted
+    );
+
     // Record vote in a new PDA
+    vote.version = JudgeVoteAccount::VERSION;
     vote.judge = ctx.accounts.judge.key();
     vote.contest = ctx.accounts.contest.key();
     vote.winner = winner;
     vote.voted_at = clock.unix_timestamp;
     vote.bump = ctx.bumps.vote;
+    vote.track = track;
+
+    let contest = &mut ctx.accounts.contest;
+    contest.open_accounts = contest.open_accounts.saturating_add(1);
 
-    msg!("Judge {} voted for winner: {}", vote.judge, winner);
+    msg!("Judge {} voted for winner: {} (track {})", vote.judge, winner, track);
     Ok(())
 }
 
 #[derive(Accounts)]
+#[instruction(winner: Pubkey, track: u8)]
 pub struct JudgeVote<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(mut)]
     pub contest: Account<'info, Contest>,
 
@@ -52,11 +83,18 @@ pub struct JudgeVote<'info> {
         init,
         payer = judge,
         space = 8 + JudgeVoteAccount::INIT_SPACE,
-        seeds = [b"vote", contest.key().as_ref(), judge.key().as_ref()],
+        seeds = [b"vote", contest.key().as_ref(), judge.key().as_ref(), &[track]],
         bump
     )]
     pub vote: Account<'info, JudgeVoteAccount>,
 
+    /// CHECK: Version 0 vote address (no track seed), only checked for emptiness
+    #[account(
+        seeds = [b"vote", contest.key().as_ref(), judge.key().as_ref()],
+        bump
+    )]
+    pub legacy_vote: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub judge: Signer<'info>,
 
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::utils::rewrite_account;
+
+/// Upgrades a contest and its submissions and votes from layout version 0
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **UncheckedAccount + manual decoding** - `Account<Contest>` would reject the
+///    old layout, so the contest is read through `decode_contest`
+/// 2. **remaining_accounts** - Submissions and votes to upgrade are passed in,
+///    in as many transactions as needed
+/// 3. **Account resizing** - Each account is resized to its current layout, with
+///    the payer topping up rent
+/// 4. **Permissionless crank** - Migration only restates existing data, so anyone can run it
+///
+/// Accounts already on the current layout are skipped, so re-running is harmless.
+/// Upgrading the contest itself also needs every judge's version 0 vote address
+/// (`JudgeVoteAccountV0::address`), empty or not, so that existing votes are
+/// counted as open children before any of them is migrated.
+pub fn migrate_contest<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateContest<'info>>,
+) -> Result<()> {
+    let contest_info = ctx.accounts.contest.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let contest_key = contest_info.key();
+    let clock = Clock::get()?;
+
+    let (mut contest, mut contest_changed, legacy_votes) =
+        match decode_contest(&contest_info.try_borrow_data()?)? {
+            VersionedContest::V0(legacy) => {
+                let legacy_votes: Vec<Pubkey> = legacy
+                    .judges
+                    .iter()
+                    .map(|judge| JudgeVoteAccountV0::address(&contest_key, judge))
+                    .collect();
+                (legacy.upgrade(clock.unix_timestamp), true, legacy_votes)
+            }
+            VersionedContest::V1(current) => (*current, false, Vec::new()),
+        };
+
+    // Version 0 contests did not count votes as open children, so count them
+    // all now; otherwise close_contest could run with votes still holding rent
+    for address in legacy_votes.iter() {
+        let vote = ctx
+            .remaining_accounts
+            .iter()
+            .find(|account| account.key == address)
+            .ok_or(ErrorCode::MissingLegacyVote)?;
+        if vote.owner == &crate::ID && !vote.data_is_empty() {
+            contest.open_accounts = contest.open_accounts.saturating_add(1);
+        }
+    }
+
+    let mut submissions_migrated: u32 = 0;
+    let mut votes_migrated: u32 = 0;
+
+    for account in ctx.remaining_accounts.iter() {
+        // Judges who never voted: passed only to prove there is nothing to count
+        if account.data_is_empty() && legacy_votes.contains(account.key) {
+            continue;
+        }
+        require!(
+            account.owner == &crate::ID && account.is_writable,
+            ErrorCode::InvalidMigrationAccount
+        );
+
+        let data = account.try_borrow_data()?;
+        if data.starts_with(Submission::DISCRIMINATOR) {
+            let VersionedSubmission::V0(legacy) = decode_submission(&data, &contest_key)? else {
+                continue;
+            };
+            drop(data);
+            require_keys_eq!(legacy.contest, contest_key, ErrorCode::InvalidMigrationAccount);
+
+            let submission = legacy.upgrade();
+            let space = Submission::space(submission.submission_url.len(), &submission.metadata);
+            rewrite_account(account, &submission, space, &payer, &system_program)?;
+            submissions_migrated += 1;
+        } else if data.starts_with(JudgeVoteAccount::DISCRIMINATOR) {
+            let VersionedJudgeVote::V0(legacy) = decode_vote(&data)? else {
+                continue;
+            };
+            drop(data);
+            require_keys_eq!(legacy.contest, contest_key, ErrorCode::InvalidMigrationAccount);
+
+            let vote = legacy.upgrade();
+            rewrite_account(account, &vote, 8 + JudgeVoteAccount::INIT_SPACE, &payer, &system_program)?;
+            votes_migrated += 1;
+        } else {
+            return err!(ErrorCode::InvalidMigrationAccount);
+        }
+        contest_changed = true;
+    }
+
+    if contest_changed {
+        rewrite_account(&contest_info, &contest, 8 + Contest::INIT_SPACE, &payer, &system_program)?;
+    }
+
+    msg!(
+        "Contest {} migrated to v{}: {} submissions, {} votes upgraded",
+        contest.contest_id,
+        Contest::VERSION,
+        submissions_migrated,
+        votes_migrated
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateContest<'info> {
+    #[account(mut, owner = crate::ID)]
+    /// CHECK: Decoded manually, since it may still be on an old layout
+    pub contest: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -6,6 +6,46 @@ pub mod update_submission;
 pub mod judge_vote;
 pub mod distribute_prizes;
 pub mod reclaim_funds;
+pub mod configure_registration;
+pub mod register;
+pub mod configure_entry_fee;
+pub mod settle_entry_fees;
+pub mod refund_entry_fee;
+pub mod set_allowlist_root;
+pub mod configure_participation_gate;
+pub mod configure_sealed_submissions;
+pub mod reveal_entry;
+pub mod configure_tracks;
+pub mod disqualify_submission;
+pub mod configure_late_submissions;
+pub mod add_milestone;
+pub mod submit_milestone_proof;
+pub mod approve_milestone;
+pub mod update_contest;
+pub mod extend_deadline;
+pub mod close_registration;
+pub mod close_submission;
+pub mod close_vote;
+pub mod close_milestone;
+pub mod close_contest;
+pub mod initialize_registry;
+pub mod create_series;
+pub mod fund_series;
+pub mod withdraw_series_budget;
+pub mod open_next_round;
+pub mod configure_submission_start;
+pub mod initialize_config;
+pub mod update_config;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod withdraw_treasury;
+pub mod set_protocol_pause;
+pub mod set_contest_pause;
+pub mod propose_creator;
+pub mod accept_creator;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod migrate_contest;
 
 pub use create_contest::*;
 pub use fund_contest::*;
@@ -15,3 +55,43 @@ pub use update_submission::*;
 pub use judge_vote::*;
 pub use distribute_prizes::*;
 pub use reclaim_funds::*;
+pub use configure_registration::*;
+pub use register::*;
+pub use configure_entry_fee::*;
+pub use settle_entry_fees::*;
+pub use refund_entry_fee::*;
+pub use set_allowlist_root::*;
+pub use configure_participation_gate::*;
+pub use configure_sealed_submissions::*;
+pub use reveal_entry::*;
+pub use configure_tracks::*;
+pub use disqualify_submission::*;
+pub use configure_late_submissions::*;
+pub use add_milestone::*;
+pub use submit_milestone_proof::*;
+pub use approve_milestone::*;
+pub use update_contest::*;
+pub use extend_deadline::*;
+pub use close_registration::*;
+pub use close_submission::*;
+pub use close_vote::*;
+pub use close_milestone::*;
+pub use close_contest::*;
+pub use initialize_registry::*;
+pub use create_series::*;
+pub use fund_series::*;
+pub use withdraw_series_budget::*;
+pub use open_next_round::*;
+pub use configure_submission_start::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use withdraw_treasury::*;
+pub use set_protocol_pause::*;
+pub use set_contest_pause::*;
+pub use propose_creator::*;
+pub use accept_creator::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use migrate_contest::*;
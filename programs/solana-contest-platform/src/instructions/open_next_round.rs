@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::utils::transfer_lamports_from_pda;
+
+/// Opens and funds the next round of a series once it is due
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Permissionless crank** - Anyone can open a due round; the cranker pays
+///    the new accounts' rent and is reimbursed from the series vault
+/// 2. **invoke_signed** - Vault PDA funds the round's escrow and the reimbursement
+/// 3. **Shared initialization** - Rounds are ordinary contests created from the template
+pub fn open_next_round(ctx: Context<OpenNextRound>) -> Result<()> {
+    let series = &ctx.accounts.series;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    require!(!series.is_finished(), ErrorCode::SeriesFinished);
+    require!(now >= series.next_round_at, ErrorCode::SeriesRoundNotDue);
+
+    // Rent the cranker paid for accounts created by this instruction
+    let index_created = ctx.accounts.creator_index.creator == Pubkey::default();
+    let page_created = ctx.accounts.index_page.contest_ids.is_empty();
+    let mut reimbursement = ctx.accounts.contest.to_account_info().lamports();
+    if index_created {
+        reimbursement += ctx.accounts.creator_index.to_account_info().lamports();
+    }
+    if page_created {
+        reimbursement += ctx.accounts.index_page.to_account_info().lamports();
+    }
+
+    // The vault must cover the prize and reimbursement and stay rent-exempt
+    let needed = series
+        .prize_amount
+        .checked_add(reimbursement)
+        .ok_or(ErrorCode::CalculationOverflow)?;
+    let remaining = ctx
+        .accounts
+        .vault
+        .lamports()
+        .checked_sub(needed)
+        .ok_or(ErrorCode::SeriesBudgetExhausted)?;
+    require!(
+        remaining == 0 || remaining >= Rent::get()?.minimum_balance(0),
+        ErrorCode::SeriesBudgetExhausted
+    );
+
+    let series_key = series.key();
+    let vault_seeds: &[&[u8]] = &[
+        b"series_vault",
+        series_key.as_ref(),
+        &[series.vault_bump],
+    ];
+    transfer_lamports_from_pda(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[vault_seeds],
+        series.prize_amount,
+    )?;
+    transfer_lamports_from_pda(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.cranker.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[vault_seeds],
+        reimbursement,
+    )?;
+
+    // Create the round from the template, already funded and Active
+    let contest_id = ctx.accounts.registry.contest_count;
+    let contest = &mut ctx.accounts.contest;
+    contest.initialize(series.creator, contest_id, ctx.bumps.contest, now);
+    contest.title = series.title.clone();
+    contest.description = series.description.clone();
+    contest.prize_amount = series.prize_amount;
+    contest.submission_deadline = now
+        .checked_add(series.round_duration)
+        .ok_or(ErrorCode::CalculationOverflow)?;
+    contest.judges = series.judges.clone();
+    contest.approval_threshold = series.approval_threshold;
+    contest.series = Some(series_key);
+    contest.reclaim_delay = ctx.accounts.config.reclaim_delay;
+    contest.platform_fee_bps = ctx.accounts.config.platform_fee_bps;
+    contest.funded = true;
+    contest.status = ContestStatus::Active;
+    let contest_key = contest.key();
+
+    ctx.accounts.registry.advance()?;
+    ctx.accounts.creator_index.record(
+        &mut ctx.accounts.index_page,
+        ctx.accounts.series.creator,
+        contest_id,
+        ctx.bumps.creator_index,
+        ctx.bumps.index_page,
+    )?;
+
+    // Schedule the next slot after now, skipping any missed while nobody cranked
+    let series = &mut ctx.accounts.series;
+    let missed = (now - series.next_round_at) / series.round_interval;
+    series.next_round_at = series
+        .next_round_at
+        .saturating_add((missed + 1).saturating_mul(series.round_interval));
+    series.rounds_opened += 1;
+    series.current_contest = Some(contest_key);
+
+    msg!(
+        "Series round {} opened as contest {} (reimbursed {} lamports)",
+        series.rounds_opened,
+        contest_id,
+        reimbursement
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OpenNextRound<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"series", series.creator.as_ref(), &series.series_id.to_le_bytes()],
+        bump = series.bump
+    )]
+    pub series: Account<'info, Series>,
+
+    #[account(
+        mut,
+        seeds = [b"series_vault", series.key().as_ref()],
+        bump = series.vault_bump
+    )]
+    /// CHECK: Series vault PDA validated by seeds
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, Registry>,
+
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + Contest::INIT_SPACE,
+        seeds = [b"contest", series.creator.as_ref(), &registry.contest_count.to_le_bytes()],
+        bump
+    )]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + CreatorIndex::INIT_SPACE,
+        seeds = [b"creator_index", series.creator.as_ref()],
+        bump
+    )]
+    pub creator_index: Account<'info, CreatorIndex>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + CreatorIndexPage::INIT_SPACE,
+        seeds = [
+            b"creator_index",
+            series.creator.as_ref(),
+            &creator_index.next_page().to_le_bytes()
+        ],
+        bump
+    )]
+    pub index_page: Account<'info, CreatorIndexPage>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", series.creator.as_ref(), &registry.contest_count.to_le_bytes()],
+        bump
+    )]
+    /// CHECK: Escrow PDA of the new round validated by seeds
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
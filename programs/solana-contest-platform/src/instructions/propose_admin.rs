@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Proposes a new config admin (step 1 of 2)
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Two-step authority transfer** - Control only moves once the new key
+///    signs `accept_admin`, so a typo cannot lock the protocol
+///
+/// Proposing `None` cancels a pending transfer.
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.pending_admin = new_admin;
+
+    match new_admin {
+        Some(key) => msg!("Admin transfer proposed to {}", key),
+        None => msg!("Pending admin transfer cancelled"),
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
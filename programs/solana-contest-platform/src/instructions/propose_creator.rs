@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Proposes a new contest authority (step 1 of 2)
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Two-step authority transfer** - Control only moves once the new key
+///    signs `accept_creator`, so a typo cannot strand the contest
+/// 2. **Stable PDA seeds** - `creator` stays in the seeds; only `authority` changes
+///
+/// Proposing `None` cancels a pending handover.
+pub fn propose_creator(ctx: Context<ProposeCreator>, new_authority: Option<Pubkey>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    contest.pending_authority = new_authority;
+
+    match new_authority {
+        Some(key) => msg!("Contest {} handover proposed to {}", contest.contest_id, key),
+        None => msg!("Contest {} handover cancelled", contest.contest_id),
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeCreator<'info> {
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    pub authority: Signer<'info>,
+}
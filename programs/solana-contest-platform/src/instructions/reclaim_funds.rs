@@ -1,56 +1,85 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::ErrorCode;
+use crate::utils::transfer_lamports_from_pda;
 
 /// Reclaim unused funds after contest expiry
 ///
 /// # Anchor Concepts Demonstrated:
-/// 1. **Time-locked operations** - 30-day grace period before reclaim
-/// 2. **Fund recovery pattern** - Allows creator to retrieve unused funds
+/// 1. **Time-locked operations** - Configured grace period before reclaim
+/// 2. **Fund recovery pattern** - Allows the contest authority to retrieve unused funds
 /// 3. **State-based validation** - Only works if contest not completed
+/// 4. **Cancellation** - Marks the contest Cancelled so entry fees become refundable
+/// 5. **Bounty timeout** - Unapproved milestones return to the authority after the time-lock
 pub fn reclaim_funds(ctx: Context<ReclaimFunds>) -> Result<()> {
     let contest = &ctx.accounts.contest;
     let clock = Clock::get()?;
 
-    // Validate conditions for reclaiming
-    require!(
-        contest.status != ContestStatus::Completed,
-        ErrorCode::ContestAlreadyCompleted
-    );
+    // Allow reclaim once the contest's reclaim delay has passed since judging
+    // opens (time-lock mechanism), so late/reveal windows and deadline
+    // extensions never eat into the judges' time. Milestones a bounty winner
+    // has not had approved by then return to the authority too
+    contest.check_reclaimable(clock.unix_timestamp)?;
 
-    // Allow reclaim after 30 days past deadline (time-lock mechanism)
-    let reclaim_period = contest.submission_deadline + (30 * 24 * 60 * 60);
-    require!(
-        clock.unix_timestamp >= reclaim_period,
-        ErrorCode::ReclaimPeriodNotReached
-    );
+    // Transfer remaining funds back to the authority, leaving lamport entry fees
+    // in escrow for participants to be refunded from
+    let held_fees = if contest.entry_fee_mint.is_none() {
+        contest.entry_fee_pool
+    } else {
+        0
+    };
+    let reclaim_amount = ctx
+        .accounts
+        .escrow
+        .lamports()
+        .saturating_sub(held_fees);
 
-    // Transfer all remaining funds back to creator
-    let escrow_balance = ctx.accounts.escrow.to_account_info().lamports();
+    let contest_id = contest.contest_id.to_le_bytes();
+    let escrow_seeds: &[&[u8]] = &[
+        b"escrow",
+        contest.creator.as_ref(),
+        &contest_id,
+        &[ctx.bumps.escrow],
+    ];
+    transfer_lamports_from_pda(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[escrow_seeds],
+        reclaim_amount,
+    )?;
 
-    **ctx.accounts.escrow.to_account_info().try_borrow_mut_lamports()? = 0;
-    **ctx.accounts.creator.to_account_info().try_borrow_mut_lamports()? += escrow_balance;
+    let contest = &mut ctx.accounts.contest;
+    contest.settle(ContestStatus::Cancelled, clock.unix_timestamp);
 
-    msg!("Reclaimed {} lamports", escrow_balance);
+    msg!("Reclaimed {} lamports", reclaim_amount);
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct ReclaimFunds<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
-        has_one = creator @ ErrorCode::UnauthorizedCreator,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
     )]
     pub contest: Account<'info, Contest>,
 
     #[account(
         mut,
-        seeds = [b"escrow", creator.key().as_ref(), &contest.contest_id.to_le_bytes()],
-        bump = contest.bump
+        seeds = [b"escrow", contest.creator.as_ref(), &contest.contest_id.to_le_bytes()],
+        bump
     )]
     /// CHECK: Escrow PDA
     pub escrow: AccountInfo<'info>,
 
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
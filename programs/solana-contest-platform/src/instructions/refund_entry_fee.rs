@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::utils::{transfer_lamports_from_pda, transfer_tokens_from_pda};
+
+/// Refunds a participant's entry fee after the contest is cancelled
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Permissionless crank** - Anyone can push a refund; funds only go to the participant
+/// 2. **has_one constraint** - Refund recipient must be the submission's participant
+/// 3. **Idempotency flag** - `entry_fee_refunded` prevents double refunds
+pub fn refund_entry_fee(ctx: Context<RefundEntryFee>) -> Result<()> {
+    let refund_amount = ctx
+        .accounts
+        .contest
+        .refund_entry_fee(&mut ctx.accounts.submission)?;
+    let contest = &ctx.accounts.contest;
+
+    let contest_id = contest.contest_id.to_le_bytes();
+    let escrow_seeds: &[&[u8]] = &[
+        b"escrow",
+        contest.creator.as_ref(),
+        &contest_id,
+        &[ctx.bumps.escrow],
+    ];
+
+    match contest.entry_fee_mint {
+        None => {
+            transfer_lamports_from_pda(
+                &ctx.accounts.escrow.to_account_info(),
+                &ctx.accounts.participant.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &[escrow_seeds],
+                refund_amount,
+            )?;
+        }
+        Some(fee_mint_key) => {
+            let (Some(fee_mint), Some(escrow_fee_account), Some(participant_fee_account), Some(token_program)) = (
+                ctx.accounts.fee_mint.as_ref(),
+                ctx.accounts.escrow_fee_account.as_ref(),
+                ctx.accounts.participant_fee_account.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::MissingFeeAccounts);
+            };
+            require_keys_eq!(fee_mint.key(), fee_mint_key, ErrorCode::InvalidFeeMint);
+
+            transfer_tokens_from_pda(
+                escrow_fee_account,
+                participant_fee_account,
+                fee_mint,
+                &ctx.accounts.escrow.to_account_info(),
+                &token_program.to_account_info(),
+                &[escrow_seeds],
+                refund_amount,
+            )?;
+        }
+    }
+
+    msg!(
+        "Refunded {} to participant {}",
+        refund_amount,
+        ctx.accounts.participant.key()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundEntryFee<'info> {
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        has_one = participant @ ErrorCode::UnauthorizedParticipant,
+        seeds = [b"submission", contest.key().as_ref(), participant.key().as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    /// CHECK: Refund recipient, validated against submission.participant
+    #[account(mut)]
+    pub participant: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", contest.creator.as_ref(), &contest.contest_id.to_le_bytes()],
+        bump
+    )]
+    /// CHECK: Escrow PDA validated by seeds
+    pub escrow: UncheckedAccount<'info>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = fee_mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = fee_mint,
+        token::authority = participant,
+        token::token_program = token_program,
+    )]
+    pub participant_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::errors::ErrorCode;
+use crate::gate::check_participation_gate;
+
+/// Registers a participant during the registration window
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Marker PDA** - The Registration PDA's existence grants submit rights
+/// 2. **One registration per wallet** - PDA seeds [contest, participant] enforce uniqueness
+/// 3. **Capacity check** - `max_participants` is enforced at registration time
+pub fn register(
+    ctx: Context<Register>,
+    allowlist_proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let registration = &mut ctx.accounts.registration;
+    let clock = Clock::get()?;
+
+    // Registration may open before funding so workshops can fill up early
+    require!(
+        contest.status == ContestStatus::Setup || contest.status == ContestStatus::Active,
+        ErrorCode::InvalidContestState
+    );
+    require!(
+        contest.registration_enabled(),
+        ErrorCode::RegistrationNotEnabled
+    );
+    require!(
+        clock.unix_timestamp < contest.registration_deadline,
+        ErrorCode::RegistrationClosed
+    );
+    // Allowlisted and gated contests must not let outsiders take capped seats
+    require!(
+        contest.is_allowed(&ctx.accounts.participant.key(), &allowlist_proof),
+        ErrorCode::NotOnAllowlist
+    );
+    check_participation_gate(
+        &contest.participation_gate,
+        ctx.accounts.gate_token_account.as_ref(),
+        ctx.accounts.gate_nft_metadata.as_ref().map(|m| m.as_ref()),
+    )?;
+    require!(
+        !contest.is_full(contest.registered_count),
+        ErrorCode::ParticipantCapReached
+    );
+
+    registration.participant = ctx.accounts.participant.key();
+    registration.contest = ctx.accounts.contest.key();
+    registration.registered_at = clock.unix_timestamp;
+    registration.bump = ctx.bumps.registration;
+
+    let contest = &mut ctx.accounts.contest;
+    contest.registered_count = contest.registered_count.saturating_add(1);
+    contest.open_accounts = contest.open_accounts.saturating_add(1);
+
+    msg!("Participant registered: {}", registration.participant);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Register<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        init,
+        payer = participant,
+        space = 8 + Registration::INIT_SPACE,
+        seeds = [b"registration", contest.key().as_ref(), participant.key().as_ref()],
+        bump
+    )]
+    pub registration: Account<'info, Registration>,
+
+    #[account(mut)]
+    pub participant: Signer<'info>,
+
+    /// Participant's token account for token/NFT-gated contests
+    #[account(token::authority = participant)]
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Metaplex metadata for the gate NFT, validated in check_participation_gate
+    pub gate_nft_metadata: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Reveals a sealed submission after the deadline
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Commit-reveal** - URL is only published once nobody can copy it
+/// 2. **has_one constraint** - Only the original participant can reveal
+/// 3. **Time-based Access Control** - Reveal window opens once entries close
+/// 4. **realloc constraint** - Sealed accounts start without a URL and grow to fit it
+///
+/// The URL and salt must hash to the commitment made in `submit_entry`:
+/// `sha256(url || salt)`.
+pub fn reveal_entry(
+    ctx: Context<RevealEntry>,
+    submission_url: String,
+    salt: [u8; 32],
+) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let submission = &mut ctx.accounts.submission;
+    let clock = Clock::get()?;
+
+    require!(contest.is_sealed(), ErrorCode::SubmissionsNotSealed);
+    require!(
+        contest.status == ContestStatus::Active,
+        ErrorCode::InvalidContestState
+    );
+    // Reveals wait for the late window so late entrants cannot copy
+    require!(
+        clock.unix_timestamp >= contest.late_deadline(),
+        ErrorCode::RevealWindowNotOpen
+    );
+    require!(
+        clock.unix_timestamp < contest.reveal_deadline(),
+        ErrorCode::RevealWindowClosed
+    );
+    require!(!submission.revealed, ErrorCode::AlreadyRevealed);
+
+    // Validate URL format and length
+    ctx.accounts.config.check_url_len(&submission_url)?;
+    require!(
+        submission_url.starts_with("https://"),
+        ErrorCode::InvalidUrl
+    );
+
+    // Check the reveal against the sealed commitment
+    let computed = hashv(&[submission_url.as_bytes(), &salt]).to_bytes();
+    require!(
+        submission.commitment == Some(computed),
+        ErrorCode::CommitmentMismatch
+    );
+
+    submission.submission_url = submission_url.clone();
+    submission.revealed = true;
+
+    msg!("Submission revealed: {}", submission_url);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(submission_url: String)]
+pub struct RevealEntry<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        has_one = participant @ ErrorCode::UnauthorizedParticipant,
+        seeds = [b"submission", contest.key().as_ref(), participant.key().as_ref()],
+        bump = submission.bump,
+        realloc = Submission::space(submission_url.len(), &submission.metadata),
+        realloc::payer = participant,
+        realloc::zero = false,
+    )]
+    pub submission: Account<'info, Submission>,
+
+    #[account(mut)]
+    pub participant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
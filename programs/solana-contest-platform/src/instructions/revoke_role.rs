@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::RoleUpdated;
+
+/// Revokes an operator role, returning its rent to the authority
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **close constraint** - The role PDA is closed, so the delegate loses every permission at once
+/// 2. **has_one constraint** - Only the contest authority can revoke
+///
+/// Works in any contest state, so roles can be cleaned up before `close_contest`.
+pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    contest.open_accounts = contest.open_accounts.saturating_sub(1);
+
+    emit!(RoleUpdated {
+        contest: contest.key(),
+        delegate: ctx.accounts.role.delegate,
+        permissions: 0,
+    });
+
+    msg!("Revoked role of {}", ctx.accounts.role.delegate);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        close = authority,
+        has_one = contest,
+        seeds = [b"role", contest.key().as_ref(), role.delegate.as_ref()],
+        bump = role.bump
+    )]
+    pub role: Account<'info, ContestRole>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::AllowlistRootUpdated;
+
+/// Rotates (or clears) the contest's Merkle allowlist root
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Delegated permission** - The authority or a MANAGE_ALLOWLIST operator can rotate it
+/// 2. **Events** - emit! records every change for transparency
+/// 3. **Time-based Access Control** - Rotation is frozen at the deadline
+pub fn set_allowlist_root(
+    ctx: Context<SetAllowlistRoot>,
+    new_root: Option<[u8; 32]>,
+) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+    let clock = Clock::get()?;
+
+    require!(
+        contest.status == ContestStatus::Setup || contest.status == ContestStatus::Active,
+        ErrorCode::InvalidContestState
+    );
+    require!(
+        clock.unix_timestamp < contest.submission_deadline,
+        ErrorCode::SubmissionDeadlinePassed
+    );
+
+    let old_root = contest.allowlist_root;
+    contest.allowlist_root = new_root;
+
+    emit!(AllowlistRootUpdated {
+        contest: contest.key(),
+        old_root,
+        new_root,
+        updated_at: clock.unix_timestamp,
+    });
+
+    msg!("Allowlist root updated for contest {}", contest.contest_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAllowlistRoot<'info> {
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", contest.key().as_ref(), operator.key().as_ref()],
+        bump = role.bump
+    )]
+    pub role: Option<Account<'info, ContestRole>>,
+}
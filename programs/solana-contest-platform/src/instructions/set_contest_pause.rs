@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::PauseUpdated;
+
+/// Pauses or resumes a single contest
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Circuit breaker** - Guarded instructions stop for this contest only
+/// 2. **Delegated permission** - The authority or a PAUSE operator can pause the contest
+///
+/// Time spent paused is added to `reclaim_available_at`, so the authority
+/// cannot pause judging, wait out the reclaim delay, then resume and reclaim
+/// before judges get to vote.
+pub fn set_contest_pause(ctx: Context<SetContestPause>, paused: bool) -> Result<()> {
+    let contest = &mut ctx.accounts.contest;
+
+    require!(
+        matches!(contest.status, ContestStatus::Setup | ContestStatus::Active),
+        ErrorCode::InvalidContestState
+    );
+    let now = Clock::get()?.unix_timestamp;
+    contest.set_paused(paused, now);
+
+    emit!(PauseUpdated {
+        contest: Some(contest.key()),
+        paused,
+        updated_at: now,
+    });
+
+    msg!("Contest {} {}", contest.contest_id, if paused { "paused" } else { "resumed" });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetContestPause<'info> {
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", contest.key().as_ref(), operator.key().as_ref()],
+        bump = role.bump
+    )]
+    pub role: Option<Account<'info, ContestRole>>,
+}
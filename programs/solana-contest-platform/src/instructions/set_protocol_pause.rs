@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::PauseUpdated;
+
+/// Pauses or resumes the whole protocol
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Circuit breaker** - Instructions guarded by `#[access_control]` stop across
+///    every contest; refunds, closes and withdrawals keep working
+/// 2. **has_one constraint** - Only the config admin can flip the switch
+pub fn set_protocol_pause(ctx: Context<SetProtocolPause>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.paused = paused;
+
+    emit!(PauseUpdated {
+        contest: None,
+        paused,
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Protocol {}", if paused { "paused" } else { "resumed" });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetProtocolPause<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::utils::{transfer_lamports_from_pda, transfer_tokens_from_pda};
+
+/// Splits collected entry fees between the winner and the organizer
+///
+/// The organizer share goes to the current `contest.authority`, so a contest
+/// handed over with `propose_creator` pays whoever runs it now.
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Permissionless crank** - Anyone can settle once the winner is known
+/// 2. **PDA Signing** - Escrow PDA signs both SOL and token transfers
+/// 3. **u128 intermediate math** - Basis-point split cannot overflow
+pub fn settle_entry_fees(ctx: Context<SettleEntryFees>) -> Result<()> {
+    let (prize_share, organizer_share) = ctx.accounts.contest.settle_entry_fees()?;
+    let contest = &ctx.accounts.contest;
+
+    let contest_id = contest.contest_id.to_le_bytes();
+    let escrow_seeds: &[&[u8]] = &[
+        b"escrow",
+        contest.creator.as_ref(),
+        &contest_id,
+        &[ctx.bumps.escrow],
+    ];
+
+    match contest.entry_fee_mint {
+        None => {
+            let system_program = ctx.accounts.system_program.to_account_info();
+            let escrow = ctx.accounts.escrow.to_account_info();
+            transfer_lamports_from_pda(
+                &escrow,
+                &ctx.accounts.winner.to_account_info(),
+                &system_program,
+                &[escrow_seeds],
+                prize_share,
+            )?;
+            transfer_lamports_from_pda(
+                &escrow,
+                &ctx.accounts.authority.to_account_info(),
+                &system_program,
+                &[escrow_seeds],
+                organizer_share,
+            )?;
+        }
+        Some(fee_mint_key) => {
+            let (Some(fee_mint), Some(escrow_fee_account), Some(winner_fee_account), Some(authority_fee_account), Some(token_program)) = (
+                ctx.accounts.fee_mint.as_ref(),
+                ctx.accounts.escrow_fee_account.as_ref(),
+                ctx.accounts.winner_fee_account.as_ref(),
+                ctx.accounts.authority_fee_account.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(ErrorCode::MissingFeeAccounts);
+            };
+            require_keys_eq!(fee_mint.key(), fee_mint_key, ErrorCode::InvalidFeeMint);
+
+            let escrow = ctx.accounts.escrow.to_account_info();
+            let token_program = token_program.to_account_info();
+            transfer_tokens_from_pda(
+                escrow_fee_account,
+                winner_fee_account,
+                fee_mint,
+                &escrow,
+                &token_program,
+                &[escrow_seeds],
+                prize_share,
+            )?;
+            transfer_tokens_from_pda(
+                escrow_fee_account,
+                authority_fee_account,
+                fee_mint,
+                &escrow,
+                &token_program,
+                &[escrow_seeds],
+                organizer_share,
+            )?;
+        }
+    }
+
+    msg!(
+        "Entry fees settled: {} to winner, {} to organizer",
+        prize_share,
+        organizer_share
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleEntryFees<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", contest.creator.as_ref(), &contest.contest_id.to_le_bytes()],
+        bump
+    )]
+    /// CHECK: Escrow PDA validated by seeds
+    pub escrow: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the winner recorded by distribute_prizes. Track contests
+    /// record no single winner and pay no fee prize share.
+    #[account(
+        mut,
+        constraint = contest.winner.is_none_or(|w| w == winner.key()) @ ErrorCode::InvalidWinner
+    )]
+    pub winner: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the current contest authority (organizer share recipient)
+    #[account(
+        mut,
+        address = contest.authority @ ErrorCode::UnauthorizedCreator
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = fee_mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = fee_mint,
+        token::authority = winner,
+        token::token_program = token_program,
+    )]
+    pub winner_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = fee_mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub authority_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,17 +1,32 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
 use crate::state::*;
 use crate::errors::ErrorCode;
+use crate::gate::check_participation_gate;
+use crate::utils::{first_sponsored_instruction, pay_from_gas_pool};
 
 /// Submit an entry to a contest
 ///
 /// # Anchor Concepts Demonstrated:
 /// 1. **Composite PDA Seeds** - Submission PDA uses both contest and participant as seeds
 /// 2. **String Validation** - Validates URL format and length
-/// 3. **Time-based Access Control** - Uses Clock sysvar to enforce deadline
+/// 3. **Time-based Access Control** - Uses Clock sysvar to enforce the start time and deadline
 /// 4. **Saturating Arithmetic** - Prevents overflow when incrementing counters
+/// 5. **Optional Accounts** - Registration PDA and fee accounts only required when configured
+/// 6. **Token Interface CPI** - Token entry fees work with SPL Token and Token-2022
+/// 7. **Merkle Proofs** - Invite-only contests verify the signer against an allowlist root
+/// 8. **Token/NFT Gating** - Holdings checked from a participant-owned token account
+/// 9. **Dynamic Space** - Account sized to the URL actually submitted
+/// 10. **Gas Sponsorship** - Sponsored contests refund the fee and rent from the gas pool PDA
+///     to `payer`, which is the participant or a fee-payer relayer. The fee is repaid
+///     once per transaction, and covers two signatures only for the configured relayer
 pub fn submit_entry(
     ctx: Context<SubmitEntry>,
     submission_url: String,
+    allowlist_proof: Vec<[u8; 32]>,
+    commitment: Option<[u8; 32]>,
+    track: u8,
 ) -> Result<()> {
     let contest = &ctx.accounts.contest;
     let submission = &mut ctx.accounts.submission;
@@ -22,50 +37,261 @@ pub fn submit_entry(
         contest.status == ContestStatus::Active,
         ErrorCode::InvalidContestState
     );
+    // Announced contests may be funded before submissions open
     require!(
-        clock.unix_timestamp < contest.submission_deadline,
+        clock.unix_timestamp >= contest.submissions_open_at(),
+        ErrorCode::SubmissionsNotOpen
+    );
+    // Entries after the deadline are accepted during the late window, if any
+    require!(
+        clock.unix_timestamp < contest.late_deadline(),
         ErrorCode::SubmissionDeadlinePassed
     );
+    let lateness = clock
+        .unix_timestamp
+        .saturating_sub(contest.submission_deadline)
+        .max(0);
+
+    require!(
+        (track as usize) < contest.track_count(),
+        ErrorCode::InvalidTrack
+    );
 
-    // Validate submission URL format and length
-    require!(submission_url.len() <= 200, ErrorCode::UrlTooLong);
+    // Invite-only contests require a Merkle proof of the signer's key
     require!(
-        submission_url.starts_with("https://"),
-        ErrorCode::InvalidUrl
+        contest.is_allowed(&ctx.accounts.participant.key(), &allowlist_proof),
+        ErrorCode::NotOnAllowlist
     );
 
+    // Token- and NFT-gated contests check the participant's holdings
+    check_participation_gate(
+        &contest.participation_gate,
+        ctx.accounts.gate_token_account.as_ref(),
+        ctx.accounts.gate_nft_metadata.as_ref().map(|m| m.as_ref()),
+    )?;
+
+    // Registration-gated contests only accept registered wallets; their
+    // opening time is covered by submissions_open_at above
+    if contest.registration_enabled() {
+        require!(
+            ctx.accounts.registration.is_some(),
+            ErrorCode::NotRegistered
+        );
+    } else {
+        require!(
+            !contest.is_full(contest.submission_count),
+            ErrorCode::ParticipantCapReached
+        );
+    }
+
+    // Sealed contests take only a commitment; the URL is revealed later
+    if contest.is_sealed() {
+        require!(
+            commitment.is_some() && submission_url.is_empty(),
+            ErrorCode::InvalidSubmissionMode
+        );
+    } else {
+        require!(commitment.is_none(), ErrorCode::InvalidSubmissionMode);
+
+        // Validate submission URL format and length
+        ctx.accounts.config.check_url_len(&submission_url)?;
+        require!(
+            submission_url.starts_with("https://"),
+            ErrorCode::InvalidUrl
+        );
+    }
+
+    // Collect the entry fee into the contest escrow
+    let entry_fee = contest.entry_fee;
+    if contest.has_entry_fee() {
+        let escrow = ctx
+            .accounts
+            .escrow
+            .as_ref()
+            .ok_or(ErrorCode::MissingFeeAccounts)?;
+
+        match contest.entry_fee_mint {
+            None => {
+                let cpi_context = CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.participant.to_account_info(),
+                        to: escrow.to_account_info(),
+                    },
+                );
+                transfer(cpi_context, entry_fee)?;
+            }
+            Some(fee_mint_key) => {
+                let (Some(fee_mint), Some(participant_fee_account), Some(escrow_fee_account), Some(token_program)) = (
+                    ctx.accounts.fee_mint.as_ref(),
+                    ctx.accounts.participant_fee_account.as_ref(),
+                    ctx.accounts.escrow_fee_account.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                ) else {
+                    return err!(ErrorCode::MissingFeeAccounts);
+                };
+                require_keys_eq!(fee_mint.key(), fee_mint_key, ErrorCode::InvalidFeeMint);
+
+                let cpi_context = CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: participant_fee_account.to_account_info(),
+                        mint: fee_mint.to_account_info(),
+                        to: escrow_fee_account.to_account_info(),
+                        authority: ctx.accounts.participant.to_account_info(),
+                    },
+                );
+                transfer_checked(cpi_context, entry_fee, fee_mint.decimals)?;
+            }
+        }
+    }
+
     // Initialize submission account
+    submission.version = Submission::VERSION;
     submission.participant = ctx.accounts.participant.key();
     submission.contest = ctx.accounts.contest.key();
     submission.submission_url = submission_url.clone();
     submission.submitted_at = clock.unix_timestamp;
     submission.last_modified = clock.unix_timestamp;
     submission.bump = ctx.bumps.submission;
+    submission.entry_fee_paid = entry_fee;
+    submission.entry_fee_refunded = false;
+    submission.commitment = commitment;
+    submission.revealed = commitment.is_none();
+    submission.track = track;
+    submission.disqualify_votes = 0;
+    submission.disqualify_reason = [0; 32];
+    submission.disqualified = false;
+    submission.lateness = lateness;
+    submission.rent_sponsored = 0;
+    submission.metadata = SubmissionMetadata::default();
+
+    // Sponsored contests pay the participant's fee and rent back out of the
+    // gas pool; the rent returns to the pool when the submission is closed
+    if contest.gas_sponsorship_enabled {
+        let tx_fee = if first_sponsored_instruction(&ctx.accounts.instructions)? {
+            sponsored_tx_fee(
+                &ctx.accounts.payer.key(),
+                &ctx.accounts.participant.key(),
+                ctx.accounts.config.relayer,
+            )
+        } else {
+            0
+        };
+        let rent = Rent::get()?.minimum_balance(submission.to_account_info().data_len());
+        let sponsored = pay_from_gas_pool(
+            &ctx.accounts.gas_pool.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &contest.key(),
+            ctx.bumps.gas_pool,
+            tx_fee + rent,
+        )?;
+        if sponsored {
+            submission.rent_sponsored = rent;
+            msg!("Gas pool reimbursed {} lamports", tx_fee + rent);
+        } else {
+            msg!("Gas pool exhausted; submission not sponsored");
+        }
+    }
 
     // Increment submission count using saturating_add to prevent overflow
     let contest = &mut ctx.accounts.contest;
     contest.submission_count = contest.submission_count.saturating_add(1);
+    contest.open_accounts = contest.open_accounts.saturating_add(1);
+    contest.collect_entry_fee()?;
 
-    msg!("Submission recorded: {}", submission_url);
+    if lateness > 0 {
+        msg!("Late submission: {}s past deadline", lateness);
+    }
+    if submission.revealed {
+        msg!("Submission recorded: {}", submission.submission_url);
+    } else {
+        msg!("Sealed submission recorded");
+    }
     Ok(())
 }
 
 #[derive(Accounts)]
+#[instruction(submission_url: String)]
 pub struct SubmitEntry<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(mut)]
     pub contest: Account<'info, Contest>,
 
     #[account(
         init,
-        payer = participant,
-        space = 8 + Submission::INIT_SPACE,
+        payer = payer,
+        space = Submission::space(submission_url.len(), &SubmissionMetadata::default()),
         seeds = [b"submission", contest.key().as_ref(), participant.key().as_ref()],
         bump
     )]
     pub submission: Account<'info, Submission>,
 
+    #[account(
+        seeds = [b"registration", contest.key().as_ref(), participant.key().as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Option<Account<'info, Registration>>,
+
     #[account(mut)]
     pub participant: Signer<'info>,
 
+    /// Pays the submission rent: the participant, or a relayer acting as fee payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"gas_pool", contest.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Gas pool PDA - reimburses sponsored participants
+    pub gas_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", contest.creator.as_ref(), &contest.contest_id.to_le_bytes()],
+        bump
+    )]
+    /// CHECK: Escrow PDA validated by seeds - receives lamport entry fees
+    pub escrow: Option<UncheckedAccount<'info>>,
+
+    pub fee_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = fee_mint,
+        token::authority = participant,
+        token::token_program = token_program,
+    )]
+    pub participant_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = fee_mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Participant's token account for token/NFT-gated contests
+    #[account(token::authority = participant)]
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Metaplex metadata for the gate NFT, validated in check_participation_gate
+    pub gate_nft_metadata: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
+
+    /// CHECK: Instructions sysvar, read so the transaction fee is reimbursed once per transaction
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
 }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Winner submits proof of a completed milestone
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Runtime Authorization Check** - Only the recorded winner can submit
+/// 2. **has_one constraint** - Milestone must belong to this contest
+///
+/// Resubmitting replaces the proof and clears earlier approvals, so judges
+/// always approve the proof that is currently on-chain.
+pub fn submit_milestone_proof(
+    ctx: Context<SubmitMilestoneProof>,
+    proof_hash: [u8; 32],
+) -> Result<()> {
+    let contest = &ctx.accounts.contest;
+    let milestone = &mut ctx.accounts.milestone;
+
+    require!(
+        contest.winner == Some(ctx.accounts.winner.key()),
+        ErrorCode::InvalidWinner
+    );
+    require!(!milestone.released, ErrorCode::MilestoneAlreadyReleased);
+
+    milestone.proof_hash = Some(proof_hash);
+    milestone.approvals = 0;
+
+    msg!("Proof submitted for milestone {}", milestone.index);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitMilestoneProof<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub contest: Account<'info, Contest>,
+
+    #[account(
+        mut,
+        has_one = contest,
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub winner: Signer<'info>,
+}
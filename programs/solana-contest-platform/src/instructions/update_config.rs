@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::ConfigUpdated;
+
+/// Updates protocol policy values
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **has_one constraint** - Only the config admin can change policy
+/// 2. **Bounded parameters** - Values are checked against the account storage caps
+///
+/// Existing contests keep the reclaim delay and platform fee they were created with.
+/// Changing `relayer` takes effect for the next sponsored transaction.
+pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.apply(&params)?;
+
+    emit!(ConfigUpdated {
+        admin: config.admin,
+        min_prize_amount: config.min_prize_amount,
+        max_judges: config.max_judges,
+        reclaim_delay: config.reclaim_delay,
+        max_url_len: config.max_url_len,
+        platform_fee_bps: config.platform_fee_bps,
+        relayer: config.relayer,
+    });
+
+    msg!("Config updated");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::ContestUpdated;
+
+/// Edits contest metadata and rules
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **Optional arguments** - `None` leaves a field unchanged
+/// 2. **Delegated permission** - The authority or an UPDATE_CONTEST operator can edit
+/// 3. **State-dependent permissions** - What may change depends on the lifecycle
+/// 4. **Events** - Every edit bumps `revision` and emits ContestUpdated
+///
+/// # Editable fields:
+/// - Setup: everything
+/// - Active, no participants yet: everything except the (already escrowed) prize
+///   and the deadline, which only moves through the capped `extend_deadline`
+/// - Active with registrations or submissions: description and metadata link only
+///
+/// Judges, approval threshold and prize stay authority-only: an UPDATE_CONTEST
+/// operator could otherwise seat a panel that decides who gets paid.
+///
+/// `metadata_uri` and `metadata_hash` are set together; an empty URI with
+/// no hash clears both.
+#[allow(clippy::too_many_arguments)]
+pub fn update_contest(
+    ctx: Context<UpdateContest>,
+    title: Option<String>,
+    description: Option<String>,
+    prize_amount: Option<u64>,
+    submission_deadline: Option<i64>,
+    judges: Option<Vec<Pubkey>>,
+    approval_threshold: Option<u8>,
+    metadata_uri: Option<String>,
+    metadata_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let contest = &mut ctx.accounts.contest;
+    let clock = Clock::get()?;
+
+    if ctx.accounts.operator.key() != contest.authority {
+        require!(
+            judges.is_none() && approval_threshold.is_none() && prize_amount.is_none(),
+            ErrorCode::UnauthorizedCreator
+        );
+    }
+
+    let material_change = title.is_some()
+        || prize_amount.is_some()
+        || submission_deadline.is_some()
+        || judges.is_some()
+        || approval_threshold.is_some();
+
+    match contest.status {
+        ContestStatus::Setup => {}
+        ContestStatus::Active => {
+            require!(prize_amount.is_none(), ErrorCode::MaterialFieldLocked);
+            // Funded contests count deadline moves against the extension cap
+            require!(submission_deadline.is_none(), ErrorCode::DeadlineLocked);
+            require!(
+                !material_change || !contest.has_participants(),
+                ErrorCode::MaterialFieldLocked
+            );
+        }
+        _ => return err!(ErrorCode::InvalidContestState),
+    }
+
+    if let Some(title) = title {
+        require!(title.len() <= 100, ErrorCode::TitleTooLong);
+        contest.title = title;
+    }
+    if let Some(description) = description {
+        require!(description.len() <= 500, ErrorCode::DescriptionTooLong);
+        contest.description = description;
+    }
+    match (metadata_uri, metadata_hash) {
+        (None, None) => {}
+        (Some(uri), None) if uri.is_empty() => {
+            contest.metadata_uri = None;
+            contest.metadata_hash = None;
+        }
+        (Some(uri), Some(hash)) => {
+            require!(uri.len() <= 200, ErrorCode::MetadataUriTooLong);
+            require!(
+                uri.starts_with("https://") || uri.starts_with("ipfs://") || uri.starts_with("ar://"),
+                ErrorCode::InvalidMetadataUri
+            );
+            contest.metadata_uri = Some(uri);
+            contest.metadata_hash = Some(hash);
+        }
+        _ => return err!(ErrorCode::MetadataHashRequired),
+    }
+    if let Some(prize_amount) = prize_amount {
+        // Track contests derive the prize from their tracks
+        require!(contest.tracks.is_empty(), ErrorCode::PrizeManagedByTracks);
+        config.check_prize(prize_amount)?;
+        contest.prize_amount = prize_amount;
+    }
+    if let Some(submission_deadline) = submission_deadline {
+        require!(
+            submission_deadline > clock.unix_timestamp
+                && submission_deadline > contest.submissions_open_at(),
+            ErrorCode::InvalidDeadline
+        );
+        contest.submission_deadline = submission_deadline;
+    }
+    if let Some(judges) = judges {
+        config.check_judges(&judges)?;
+        contest.judges = judges;
+    }
+    if let Some(approval_threshold) = approval_threshold {
+        contest.approval_threshold = approval_threshold;
+    }
+    // Re-check against the (possibly new) judge list
+    require!(
+        contest.approval_threshold > 0
+            && contest.approval_threshold as usize <= contest.judges.len(),
+        ErrorCode::InvalidThreshold
+    );
+
+    contest.revision = contest.revision.saturating_add(1);
+
+    emit!(ContestUpdated {
+        contest: contest.key(),
+        revision: contest.revision,
+        material: material_change,
+        updated_at: clock.unix_timestamp,
+    });
+
+    msg!("Contest {} updated to revision {}", contest.contest_id, contest.revision);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateContest<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub contest: Account<'info, Contest>,
+
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [b"role", contest.key().as_ref(), operator.key().as_ref()],
+        bump = role.bump
+    )]
+    pub role: Option<Account<'info, ContestRole>>,
+}
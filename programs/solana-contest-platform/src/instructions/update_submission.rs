@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 use crate::state::*;
 use crate::errors::ErrorCode;
+use crate::utils::{first_sponsored_instruction, pay_from_gas_pool};
 
 /// Update an existing submission before the deadline
 ///
@@ -8,13 +10,26 @@ use crate::errors::ErrorCode;
 /// 1. **has_one constraint** - Ensures only original participant can update
 /// 2. **Mutable account updates** - Modifies existing account data
 /// 3. **seeds + bump validation** - Verifies the submission PDA is correct
+/// 4. **realloc constraint** - Account grows or shrinks to fit the new content;
+///    `payer` pays (or is refunded) the rent difference
+/// 5. **Gas Sponsorship** - Sponsored contests refund the fee and any extra rent to
+///    `payer` from the gas pool; rent freed by shrinking goes back to the pool up to
+///    what it paid. Updates that change nothing are not reimbursed, and the fee
+///    is repaid at most once per transaction
 pub fn update_submission(
     ctx: Context<UpdateSubmission>,
     new_url: String,
+    new_commitment: Option<[u8; 32]>,
+    metadata: Option<SubmissionMetadata>,
 ) -> Result<()> {
     let contest = &ctx.accounts.contest;
+    let config = &ctx.accounts.config;
     let submission = &mut ctx.accounts.submission;
     let clock = Clock::get()?;
+    // realloc has already resized the account, but the deserialized copy is
+    // still the old content
+    let old_space = Submission::space(submission.submission_url.len(), &submission.metadata);
+    let changed = submission.update_changes(&new_url, &new_commitment, &metadata);
 
     // Validate timing - can only update before deadline
     require!(
@@ -22,20 +37,116 @@ pub fn update_submission(
         ErrorCode::SubmissionDeadlinePassed
     );
 
-    // Validate new URL
-    require!(new_url.len() <= 200, ErrorCode::UrlTooLong);
-    require!(new_url.starts_with("https://"), ErrorCode::InvalidUrl);
+    // Sealed contests replace the commitment; open contests replace the URL
+    // and/or project details. Details stay hidden until a sealed entry reveals.
+    if contest.is_sealed() {
+        require!(
+            new_commitment.is_some() && new_url.is_empty() && metadata.is_none(),
+            ErrorCode::InvalidSubmissionMode
+        );
+        submission.commitment = new_commitment;
+        submission.last_modified = clock.unix_timestamp;
 
-    // Update submission with new URL and timestamp
-    submission.submission_url = new_url.clone();
-    submission.last_modified = clock.unix_timestamp;
+        msg!("Sealed submission updated");
+    } else {
+        require!(new_commitment.is_none(), ErrorCode::InvalidSubmissionMode);
 
-    msg!("Submission updated: {}", new_url);
+        // An empty URL keeps the current one
+        if !new_url.is_empty() {
+            // Validate new URL
+            config.check_url_len(&new_url)?;
+            require!(new_url.starts_with("https://"), ErrorCode::InvalidUrl);
+            submission.submission_url = new_url;
+        }
+
+        if let Some(metadata) = metadata {
+            validate_metadata(config, &metadata)?;
+            submission.metadata = metadata;
+        }
+
+        submission.last_modified = clock.unix_timestamp;
+
+        msg!("Submission updated: {}", submission.submission_url);
+    }
+
+    if contest.gas_sponsorship_enabled && changed {
+        let rent = Rent::get()?;
+        let old_rent = rent.minimum_balance(old_space);
+        let new_rent = rent.minimum_balance(submission.to_account_info().data_len());
+
+        // Net the fee refund against whatever rent realloc moved
+        let (growth, returned) = if new_rent >= old_rent {
+            (new_rent - old_rent, 0)
+        } else {
+            (0, (old_rent - new_rent).min(submission.rent_sponsored))
+        };
+        let tx_fee = if first_sponsored_instruction(&ctx.accounts.instructions)? {
+            sponsored_tx_fee(
+                &ctx.accounts.payer.key(),
+                &ctx.accounts.participant.key(),
+                config.relayer,
+            )
+        } else {
+            0
+        };
+        let owed = tx_fee + growth;
+
+        if returned > owed {
+            let cpi_context = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.gas_pool.to_account_info(),
+                },
+            );
+            transfer(cpi_context, returned - owed)?;
+            submission.rent_sponsored -= returned;
+        } else if pay_from_gas_pool(
+            &ctx.accounts.gas_pool.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &contest.key(),
+            ctx.bumps.gas_pool,
+            owed - returned,
+        )? {
+            submission.rent_sponsored = submission.rent_sponsored - returned + growth;
+            msg!("Gas pool reimbursed {} lamports", owed - returned);
+        } else {
+            // The freed rent already went to the payer
+            submission.rent_sponsored -= returned;
+            msg!("Gas pool exhausted; update not sponsored");
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates project details; links are optional but must be HTTPS
+fn validate_metadata(config: &Config, metadata: &SubmissionMetadata) -> Result<()> {
+    require!(metadata.title.len() <= 64, ErrorCode::ProjectTitleTooLong);
+    require!(
+        metadata.description.len() <= 280,
+        ErrorCode::SubmissionDescriptionTooLong
+    );
+    for url in [&metadata.repo_url, &metadata.demo_url, &metadata.video_url] {
+        config.check_url_len(url)?;
+        require!(
+            url.is_empty() || url.starts_with("https://"),
+            ErrorCode::InvalidUrl
+        );
+    }
     Ok(())
 }
 
 #[derive(Accounts)]
+#[instruction(new_url: String, new_commitment: Option<[u8; 32]>, metadata: Option<SubmissionMetadata>)]
 pub struct UpdateSubmission<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(mut)]
     pub contest: Account<'info, Contest>,
 
@@ -43,9 +154,30 @@ pub struct UpdateSubmission<'info> {
         mut,
         has_one = participant @ ErrorCode::UnauthorizedParticipant,
         seeds = [b"submission", contest.key().as_ref(), participant.key().as_ref()],
-        bump = submission.bump
+        bump = submission.bump,
+        realloc = submission.space_after_update(&new_url, &metadata),
+        realloc::payer = payer,
+        realloc::zero = false,
     )]
     pub submission: Account<'info, Submission>,
 
     pub participant: Signer<'info>,
+
+    /// Pays the rent difference: the participant, or a relayer acting as fee payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"gas_pool", contest.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Gas pool PDA - reimburses sponsored participants
+    pub gas_pool: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Instructions sysvar, read so the transaction fee is reimbursed once per transaction
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
 }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::utils::transfer_lamports_from_pda;
+
+/// Withdraws unspent budget from a series vault back to its creator
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **has_one constraint** - Only the series creator can withdraw
+/// 2. **PDA Signing** - Vault PDA signs the transfer
+pub fn withdraw_series_budget(ctx: Context<WithdrawSeriesBudget>, amount: u64) -> Result<()> {
+    let series = &ctx.accounts.series;
+    let series_key = series.key();
+    let vault_seeds: &[&[u8]] = &[
+        b"series_vault",
+        series_key.as_ref(),
+        &[series.vault_bump],
+    ];
+    transfer_lamports_from_pda(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[vault_seeds],
+        amount,
+    )?;
+
+    msg!("Withdrew {} lamports from series budget", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSeriesBudget<'info> {
+    #[account(
+        has_one = creator @ ErrorCode::UnauthorizedCreator,
+    )]
+    pub series: Account<'info, Series>,
+
+    #[account(
+        mut,
+        seeds = [b"series_vault", series.key().as_ref()],
+        bump = series.vault_bump
+    )]
+    /// CHECK: Series vault PDA validated by seeds
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
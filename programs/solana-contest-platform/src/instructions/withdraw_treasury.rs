@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::utils::transfer_lamports_from_pda;
+
+/// Withdraws collected platform fees from the treasury
+///
+/// # Anchor Concepts Demonstrated:
+/// 1. **has_one constraint** - Only the config admin can withdraw
+/// 2. **PDA Signing** - Treasury PDA signs the System Program transfer
+/// 3. **Rent floor** - The treasury keeps its rent-exempt minimum so it can keep receiving fees
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let rent_minimum = Rent::get()?.minimum_balance(0);
+    let available = ctx.accounts.treasury.lamports().saturating_sub(rent_minimum);
+    require!(amount <= available, ErrorCode::InsufficientTreasuryBalance);
+
+    let treasury_seeds: &[&[u8]] = &[b"treasury", &[ctx.bumps.treasury]];
+    transfer_lamports_from_pda(
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[treasury_seeds],
+        amount,
+    )?;
+
+    msg!(
+        "Withdrew {} lamports from treasury to {}",
+        amount,
+        ctx.accounts.recipient.key()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    /// CHECK: Treasury PDA validated by seeds
+    pub treasury: UncheckedAccount<'info>,
+
+    /// CHECK: Any account chosen by the admin to receive the fees
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
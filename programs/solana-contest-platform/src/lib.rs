@@ -1,10 +1,17 @@
 use anchor_lang::prelude::*;
 
+pub mod access;
 pub mod errors;
+pub mod events;
+pub mod gate;
 pub mod instructions;
+pub mod merkle;
 pub mod state;
+pub mod utils;
 
+use access::{authorize, contest_not_paused, protocol_not_paused};
 use instructions::*;
+use state::{ConfigParams, ContestRole, ParticipationGate, SeriesTemplate, SubmissionMetadata, TrackConfig};
 
 declare_id!("9VcxDiDi8kbP6UnaVocXDcSPDwoJiDMxmECdqyALGuA4");
 
@@ -17,8 +24,8 @@ declare_id!("9VcxDiDi8kbP6UnaVocXDcSPDwoJiDMxmECdqyALGuA4");
 /// - Trustless prize distribution
 ///
 /// # Architecture
-/// - 8 Instructions for full contest lifecycle
-/// - 5 PDA types for accounts (contest, escrow, gas_pool, submission, vote)
+/// - 48 Instructions for full contest lifecycle
+/// - 15 PDA types for accounts (config, treasury, registry, creator index + pages, series, series vault, contest, escrow, gas_pool, registration, submission, vote, milestone, role)
 /// - State machine for contest status management
 /// - Time-based access control with deadlines
 ///
@@ -27,46 +34,56 @@ declare_id!("9VcxDiDi8kbP6UnaVocXDcSPDwoJiDMxmECdqyALGuA4");
 /// - Input validation
 /// - State transition validation
 /// - Time-locked operations
+/// - Protocol-wide and per-contest pause via #[access_control]
 /// - Integer overflow protection
 #[program]
 pub mod solarena {
     use super::*;
 
+    /// Creates the global contest registry; must run once after deployment
+    pub fn initialize_registry(ctx: Context<InitializeRegistry>) -> Result<()> {
+        instructions::initialize_registry::initialize_registry(ctx)
+    }
+
     /// Creates a new contest with SOL prizes
     ///
+    /// The contest ID is assigned from the global registry counter.
+    ///
     /// # Arguments
-    /// * `contest_id` - Unique identifier for the contest
     /// * `title` - Contest title (max 100 chars)
     /// * `description` - Contest description (max 500 chars)
-    /// * `prize_amount` - Prize in lamports (min 0.01 SOL)
+    /// * `prize_amount` - Prize in lamports (at least the configured minimum)
     /// * `submission_deadline` - Unix timestamp for deadline
-    /// * `judges` - List of authorized judge public keys (max 5)
+    /// * `judges` - List of authorized judge public keys (up to the configured limit)
     /// * `approval_threshold` - Votes needed for consensus (e.g., 2 of 3)
+    /// * `allowlist_root` - Optional Merkle root of allowed participants
+    #[allow(clippy::too_many_arguments)]
+    #[access_control(protocol_not_paused(&ctx.accounts.config))]
     pub fn create_contest(
         ctx: Context<CreateContest>,
-        contest_id: u64,
         title: String,
         description: String,
         prize_amount: u64,
         submission_deadline: i64,
         judges: Vec<Pubkey>,
         approval_threshold: u8,
+        allowlist_root: Option<[u8; 32]>,
     ) -> Result<()> {
         instructions::create_contest::create_contest(
             ctx,
-            contest_id,
             title,
             description,
             prize_amount,
             submission_deadline,
             judges,
             approval_threshold,
+            allowlist_root,
         )
     }
 
     /// Funds the contest escrow to activate it
     ///
-    /// Transfers the prize amount from creator to escrow PDA
+    /// Transfers the prize amount from the contest authority to escrow PDA
     /// and changes contest status from Setup to Active
     pub fn fund_contest(ctx: Context<FundContest>) -> Result<()> {
         instructions::fund_contest::fund_contest(ctx)
@@ -76,6 +93,7 @@ pub mod solarena {
     ///
     /// # Arguments
     /// * `gas_budget` - Amount of SOL to allocate for gas fees
+    #[access_control(authorize(&ctx.accounts.contest, &ctx.accounts.operator, &ctx.accounts.role, ContestRole::GAS_SPONSORSHIP))]
     pub fn enable_gas_sponsorship(
         ctx: Context<EnableGasSponsorship>,
         gas_budget: u64,
@@ -86,50 +104,497 @@ pub mod solarena {
     /// Submits an entry to the contest
     ///
     /// # Arguments
-    /// * `submission_url` - HTTPS URL to the submission (max 200 chars)
+    /// * `submission_url` - HTTPS URL to the submission (up to the configured length)
+    /// * `allowlist_proof` - Merkle proof for allowlisted contests (empty otherwise)
+    /// * `commitment` - `sha256(url || salt)` for sealed contests (URL must be empty)
+    /// * `track` - Track index to enter (0 for single-prize contests)
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
     pub fn submit_entry(
         ctx: Context<SubmitEntry>,
         submission_url: String,
+        allowlist_proof: Vec<[u8; 32]>,
+        commitment: Option<[u8; 32]>,
+        track: u8,
     ) -> Result<()> {
-        instructions::submit_entry::submit_entry(ctx, submission_url)
+        instructions::submit_entry::submit_entry(
+            ctx,
+            submission_url,
+            allowlist_proof,
+            commitment,
+            track,
+        )
     }
 
     /// Updates an existing submission before deadline
     ///
     /// # Arguments
-    /// * `new_url` - New HTTPS URL for the submission
+    /// * `new_url` - New HTTPS URL for the submission (empty keeps the current URL)
+    /// * `new_commitment` - Replacement commitment for sealed contests (URL must be empty)
+    /// * `metadata` - Project title, links and description (None keeps current details)
+    ///
+    /// The account is reallocated to fit; the participant pays or is
+    /// refunded the rent difference
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
     pub fn update_submission(
         ctx: Context<UpdateSubmission>,
         new_url: String,
+        new_commitment: Option<[u8; 32]>,
+        metadata: Option<SubmissionMetadata>,
     ) -> Result<()> {
-        instructions::update_submission::update_submission(ctx, new_url)
+        instructions::update_submission::update_submission(ctx, new_url, new_commitment, metadata)
     }
 
     /// Judge votes for a winner
     ///
     /// # Arguments
     /// * `winner` - Public key of the winning participant
+    /// * `track` - Track being judged (0 for single-prize contests)
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
     pub fn judge_vote(
         ctx: Context<JudgeVote>,
         winner: Pubkey,
+        track: u8,
     ) -> Result<()> {
-        instructions::judge_vote::judge_vote(ctx, winner)
+        instructions::judge_vote::judge_vote(ctx, winner, track)
     }
 
     /// Distributes prizes when consensus is reached
     ///
     /// Counts votes from remaining_accounts and transfers
     /// prize to winner if threshold is met
+    ///
+    /// # Arguments
+    /// * `track` - Track to settle (0 for single-prize contests)
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
     pub fn distribute_prizes<'info>(
         ctx: Context<'_, '_, 'info, 'info, DistributePrizes<'info>>,
+        track: u8,
     ) -> Result<()> {
-        instructions::distribute_prizes::distribute_prizes(ctx)
+        instructions::distribute_prizes::distribute_prizes(ctx, track)
     }
 
     /// Reclaims unused funds after contest expiry
     ///
-    /// Available 30 days after deadline if contest not completed
+    /// Available once the contest's reclaim delay has passed after judging opens,
+    /// if the contest is not completed.
+    /// Cancels the contest; lamport entry fees stay in escrow for refunds
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
     pub fn reclaim_funds(ctx: Context<ReclaimFunds>) -> Result<()> {
         instructions::reclaim_funds::reclaim_funds(ctx)
     }
+
+    /// Configures the optional registration window and participant cap
+    ///
+    /// # Arguments
+    /// * `registration_deadline` - Unix timestamp when registration closes (0 = open entry)
+    /// * `max_participants` - Maximum registrations/submissions (0 = unlimited)
+    pub fn configure_registration(
+        ctx: Context<ConfigureRegistration>,
+        registration_deadline: i64,
+        max_participants: u32,
+    ) -> Result<()> {
+        instructions::configure_registration::configure_registration(
+            ctx,
+            registration_deadline,
+            max_participants,
+        )
+    }
+
+    /// Registers the signer for a registration-gated contest
+    ///
+    /// Creates the Registration PDA that `submit_entry` requires
+    ///
+    /// # Arguments
+    /// * `allowlist_proof` - Merkle proof for allowlisted contests (empty otherwise)
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
+    pub fn register(
+        ctx: Context<Register>,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::register::register(ctx, allowlist_proof)
+    }
+
+    /// Configures the entry fee charged on each submission
+    ///
+    /// # Arguments
+    /// * `entry_fee` - Fee per entry in lamports or token base units (0 = free)
+    /// * `entry_fee_mint` - SPL mint for token fees (None = lamports)
+    /// * `prize_share_bps` - Share of collected fees paid to the winner (rest to organizer)
+    pub fn configure_entry_fee(
+        ctx: Context<ConfigureEntryFee>,
+        entry_fee: u64,
+        entry_fee_mint: Option<Pubkey>,
+        prize_share_bps: u16,
+    ) -> Result<()> {
+        instructions::configure_entry_fee::configure_entry_fee(
+            ctx,
+            entry_fee,
+            entry_fee_mint,
+            prize_share_bps,
+        )
+    }
+
+    /// Splits collected entry fees between winner and organizer
+    ///
+    /// Permissionless; available once the contest is Completed
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
+    pub fn settle_entry_fees(ctx: Context<SettleEntryFees>) -> Result<()> {
+        instructions::settle_entry_fees::settle_entry_fees(ctx)
+    }
+
+    /// Refunds a participant's entry fee from a cancelled contest
+    ///
+    /// Permissionless; the fee is always returned to the participant
+    pub fn refund_entry_fee(ctx: Context<RefundEntryFee>) -> Result<()> {
+        instructions::refund_entry_fee::refund_entry_fee(ctx)
+    }
+
+    /// Rotates or clears the Merkle allowlist before the deadline
+    ///
+    /// # Arguments
+    /// * `new_root` - New Merkle root (None opens the contest to everyone)
+    #[access_control(authorize(&ctx.accounts.contest, &ctx.accounts.operator, &ctx.accounts.role, ContestRole::MANAGE_ALLOWLIST))]
+    pub fn set_allowlist_root(
+        ctx: Context<SetAllowlistRoot>,
+        new_root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::set_allowlist_root::set_allowlist_root(ctx, new_root)
+    }
+
+    /// Restricts participation to token holders or NFT collection members
+    ///
+    /// # Arguments
+    /// * `gate` - Minimum token balance or verified collection (None removes the gate)
+    pub fn configure_participation_gate(
+        ctx: Context<ConfigureParticipationGate>,
+        gate: Option<ParticipationGate>,
+    ) -> Result<()> {
+        instructions::configure_participation_gate::configure_participation_gate(ctx, gate)
+    }
+
+    /// Enables sealed (commit-reveal) submissions
+    ///
+    /// # Arguments
+    /// * `reveal_period` - Seconds after the deadline to reveal entries (0 = open)
+    pub fn configure_sealed_submissions(
+        ctx: Context<ConfigureSealedSubmissions>,
+        reveal_period: i64,
+    ) -> Result<()> {
+        instructions::configure_sealed_submissions::configure_sealed_submissions(ctx, reveal_period)
+    }
+
+    /// Reveals a sealed submission during the reveal window
+    ///
+    /// # Arguments
+    /// * `submission_url` - HTTPS URL that was committed to
+    /// * `salt` - Salt used in the commitment
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
+    pub fn reveal_entry(
+        ctx: Context<RevealEntry>,
+        submission_url: String,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_entry::reveal_entry(ctx, submission_url, salt)
+    }
+
+    /// Splits the contest into named tracks with separate prizes
+    ///
+    /// # Arguments
+    /// * `tracks` - Track names (max 32 chars) and prizes (max 5 tracks)
+    pub fn configure_tracks(
+        ctx: Context<ConfigureTracks>,
+        tracks: Vec<TrackConfig>,
+    ) -> Result<()> {
+        instructions::configure_tracks::configure_tracks(ctx, tracks)
+    }
+
+    /// Judge vote to disqualify a submission
+    ///
+    /// Takes effect once `approval_threshold` judges cite the same reason, or
+    /// immediately when signed by the authority or a DISQUALIFY operator
+    ///
+    /// # Arguments
+    /// * `reason_hash` - Hash of the written ruling (e.g. sha256 of the reason text)
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
+    pub fn disqualify_submission(
+        ctx: Context<DisqualifySubmission>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::disqualify_submission::disqualify_submission(ctx, reason_hash)
+    }
+
+    /// Configures a grace window for late entries
+    ///
+    /// # Arguments
+    /// * `late_period` - Seconds after the deadline late entries are accepted (0 = none)
+    /// * `late_penalty_votes` - Extra judge approvals a late entry needs to win
+    pub fn configure_late_submissions(
+        ctx: Context<ConfigureLateSubmissions>,
+        late_period: i64,
+        late_penalty_votes: u8,
+    ) -> Result<()> {
+        instructions::configure_late_submissions::configure_late_submissions(
+            ctx,
+            late_period,
+            late_penalty_votes,
+        )
+    }
+
+    /// Adds a payout milestone to a bounty contest (Setup only)
+    ///
+    /// # Arguments
+    /// * `index` - Milestone position; must equal the current milestone count
+    /// * `description_hash` - Hash of the milestone specification
+    /// * `amount` - Lamports released when the milestone is approved
+    pub fn add_milestone(
+        ctx: Context<AddMilestone>,
+        index: u8,
+        description_hash: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
+        instructions::add_milestone::add_milestone(ctx, index, description_hash, amount)
+    }
+
+    /// Winner submits proof for a milestone
+    ///
+    /// # Arguments
+    /// * `proof_hash` - Hash of the delivered work (replaces any earlier proof)
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
+    pub fn submit_milestone_proof(
+        ctx: Context<SubmitMilestoneProof>,
+        proof_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::submit_milestone_proof::submit_milestone_proof(ctx, proof_hash)
+    }
+
+    /// Judge approves a milestone proof
+    ///
+    /// Releases the milestone's amount from escrow once the approval
+    /// threshold is reached
+    #[access_control(contest_not_paused(&ctx.accounts.config, &ctx.accounts.contest))]
+    pub fn approve_milestone(ctx: Context<ApproveMilestone>) -> Result<()> {
+        instructions::approve_milestone::approve_milestone(ctx)
+    }
+
+    /// Edits contest fields; `None` leaves a field unchanged
+    ///
+    /// Any field may change in Setup. Once Active, the prize is fixed, and
+    /// after the first registration or submission only the description may
+    /// change, along with the off-chain metadata link. Each call bumps
+    /// `revision` and emits `ContestUpdated`.
+    ///
+    /// # Arguments
+    /// * `metadata_uri` - Off-chain brief URI (empty string clears the link)
+    /// * `metadata_hash` - sha256 of the brief; required whenever a URI is set
+    #[allow(clippy::too_many_arguments)]
+    #[access_control(authorize(&ctx.accounts.contest, &ctx.accounts.operator, &ctx.accounts.role, ContestRole::UPDATE_CONTEST))]
+    pub fn update_contest(
+        ctx: Context<UpdateContest>,
+        title: Option<String>,
+        description: Option<String>,
+        prize_amount: Option<u64>,
+        submission_deadline: Option<i64>,
+        judges: Option<Vec<Pubkey>>,
+        approval_threshold: Option<u8>,
+        metadata_uri: Option<String>,
+        metadata_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::update_contest::update_contest(
+            ctx,
+            title,
+            description,
+            prize_amount,
+            submission_deadline,
+            judges,
+            approval_threshold,
+            metadata_uri,
+            metadata_hash,
+        )
+    }
+
+    /// Moves the submission deadline later (max 30 days in total)
+    ///
+    /// The reclaim time-lock moves back by the same amount
+    ///
+    /// # Arguments
+    /// * `new_deadline` - New Unix timestamp; must be later than the current one
+    #[access_control(authorize(&ctx.accounts.contest, &ctx.accounts.operator, &ctx.accounts.role, ContestRole::EXTEND_DEADLINE))]
+    pub fn extend_deadline(
+        ctx: Context<ExtendDeadline>,
+        new_deadline: i64,
+    ) -> Result<()> {
+        instructions::extend_deadline::extend_deadline(ctx, new_deadline)
+    }
+
+    /// Closes a registration after settlement; rent returns to the participant
+    pub fn close_registration(ctx: Context<CloseRegistration>) -> Result<()> {
+        instructions::close_registration::close_registration(ctx)
+    }
+
+    /// Closes a submission after settlement; rent returns to the participant
+    ///
+    /// In a cancelled contest the entry fee must be refunded first
+    pub fn close_submission(ctx: Context<CloseSubmission>) -> Result<()> {
+        instructions::close_submission::close_submission(ctx)
+    }
+
+    /// Closes a judge vote after settlement; rent returns to the judge
+    pub fn close_vote(ctx: Context<CloseVote>) -> Result<()> {
+        instructions::close_vote::close_vote(ctx)
+    }
+
+    /// Closes a milestone after settlement; rent returns to the contest authority
+    pub fn close_milestone(ctx: Context<CloseMilestone>) -> Result<()> {
+        instructions::close_milestone::close_milestone(ctx)
+    }
+
+    /// Closes the contest once every child account is closed, sweeping
+    /// escrow and gas pool balances and the contest's rent to the authority
+    pub fn close_contest(ctx: Context<CloseContest>) -> Result<()> {
+        instructions::close_contest::close_contest(ctx)
+    }
+
+    /// Creates a recurring contest series with a round template and budget
+    ///
+    /// # Arguments
+    /// * `series_id` - Creator-chosen identifier for the series
+    /// * `template` - Round title, prize, judges, duration and schedule
+    /// * `initial_budget` - Lamports deposited into the series vault
+    #[access_control(protocol_not_paused(&ctx.accounts.config))]
+    pub fn create_series(
+        ctx: Context<CreateSeries>,
+        series_id: u64,
+        template: SeriesTemplate,
+        initial_budget: u64,
+    ) -> Result<()> {
+        instructions::create_series::create_series(ctx, series_id, template, initial_budget)
+    }
+
+    /// Adds lamports to a series budget
+    ///
+    /// # Arguments
+    /// * `amount` - Lamports to deposit
+    pub fn fund_series(ctx: Context<FundSeries>, amount: u64) -> Result<()> {
+        instructions::fund_series::fund_series(ctx, amount)
+    }
+
+    /// Withdraws unspent series budget back to the creator
+    ///
+    /// # Arguments
+    /// * `amount` - Lamports to withdraw
+    pub fn withdraw_series_budget(ctx: Context<WithdrawSeriesBudget>, amount: u64) -> Result<()> {
+        instructions::withdraw_series_budget::withdraw_series_budget(ctx, amount)
+    }
+
+    /// Opens and funds the series' next round once it is due (anyone can call)
+    #[access_control(protocol_not_paused(&ctx.accounts.config))]
+    pub fn open_next_round(ctx: Context<OpenNextRound>) -> Result<()> {
+        instructions::open_next_round::open_next_round(ctx)
+    }
+
+    /// Schedules when submissions open, so a contest can be funded in advance
+    ///
+    /// # Arguments
+    /// * `submission_start` - Unix timestamp submissions open (0 = when funded)
+    pub fn configure_submission_start(
+        ctx: Context<ConfigureSubmissionStart>,
+        submission_start: i64,
+    ) -> Result<()> {
+        instructions::configure_submission_start::configure_submission_start(ctx, submission_start)
+    }
+
+    /// Creates the protocol config; only the program upgrade authority may call
+    ///
+    /// # Arguments
+    /// * `admin` - Key that will control policy updates
+    /// * `params` - Minimum prize, judge limit, reclaim delay, URL limit and platform fee
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        admin: Pubkey,
+        params: ConfigParams,
+    ) -> Result<()> {
+        instructions::initialize_config::initialize_config(ctx, admin, params)
+    }
+
+    /// Updates protocol policy (admin only)
+    ///
+    /// # Arguments
+    /// * `params` - New policy values; existing contests keep their reclaim delay
+    ///   and platform fee
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+        instructions::update_config::update_config(ctx, params)
+    }
+
+    /// Proposes a new config admin; `None` cancels a pending proposal
+    ///
+    /// # Arguments
+    /// * `new_admin` - Key that must call `accept_admin` to take over
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+        instructions::propose_admin::propose_admin(ctx, new_admin)
+    }
+
+    /// Accepts a pending admin transfer (signed by the proposed admin)
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::accept_admin(ctx)
+    }
+
+    /// Withdraws collected platform fees from the treasury (admin only)
+    ///
+    /// # Arguments
+    /// * `amount` - Lamports to withdraw; the rent-exempt minimum stays behind
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::withdraw_treasury::withdraw_treasury(ctx, amount)
+    }
+
+    /// Pauses or resumes the whole protocol (admin only)
+    ///
+    /// # Arguments
+    /// * `paused` - true stops guarded instructions; refunds and closes keep working
+    pub fn set_protocol_pause(ctx: Context<SetProtocolPause>, paused: bool) -> Result<()> {
+        instructions::set_protocol_pause::set_protocol_pause(ctx, paused)
+    }
+
+    /// Pauses or resumes one contest (authority or PAUSE operator)
+    ///
+    /// # Arguments
+    /// * `paused` - true stops guarded instructions for this contest
+    #[access_control(authorize(&ctx.accounts.contest, &ctx.accounts.operator, &ctx.accounts.role, ContestRole::PAUSE))]
+    pub fn set_contest_pause(ctx: Context<SetContestPause>, paused: bool) -> Result<()> {
+        instructions::set_contest_pause::set_contest_pause(ctx, paused)
+    }
+
+    /// Proposes a new contest authority; `None` cancels a pending handover
+    ///
+    /// # Arguments
+    /// * `new_authority` - Key that must call `accept_creator` to take over
+    pub fn propose_creator(ctx: Context<ProposeCreator>, new_authority: Option<Pubkey>) -> Result<()> {
+        instructions::propose_creator::propose_creator(ctx, new_authority)
+    }
+
+    /// Accepts a pending contest handover (signed by the proposed authority)
+    pub fn accept_creator(ctx: Context<AcceptCreator>) -> Result<()> {
+        instructions::accept_creator::accept_creator(ctx)
+    }
+
+    /// Grants or replaces an operator's permissions on a contest (authority only)
+    ///
+    /// # Arguments
+    /// * `delegate` - Operator wallet
+    /// * `permissions` - Bitmask of `ContestRole` permissions
+    pub fn grant_role(ctx: Context<GrantRole>, delegate: Pubkey, permissions: u8) -> Result<()> {
+        instructions::grant_role::grant_role(ctx, delegate, permissions)
+    }
+
+    /// Revokes an operator role (authority only)
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        instructions::revoke_role::revoke_role(ctx)
+    }
+
+    /// Upgrades a version 0 contest, plus any of its submissions and votes
+    /// passed as remaining accounts, to the current layout (permissionless)
+    pub fn migrate_contest<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateContest<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_contest::migrate_contest(ctx)
+    }
 }
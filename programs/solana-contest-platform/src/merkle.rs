@@ -0,0 +1,86 @@
+//! Merkle allowlist verification
+//!
+//! Leaves are `sha256(0x00 || participant)` and inner nodes are
+//! `sha256(0x01 || min(a, b) || max(a, b))`. Sorting each pair means a
+//! proof is just the list of sibling hashes, with no left/right flags,
+//! and the prefixes keep a leaf from ever being mistaken for a node.
+
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Computes the allowlist leaf for a participant
+pub fn allowlist_leaf(participant: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, participant.as_ref()]).to_bytes()
+}
+
+/// Returns true if `proof` connects `participant` to `root`
+pub fn verify_allowlist_proof(root: &[u8; 32], participant: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(allowlist_leaf(participant), |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        hashv(&[NODE_PREFIX, &left, &right]).to_bytes()
+    });
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[NODE_PREFIX, &left, &right]).to_bytes()
+    }
+
+    #[test]
+    fn single_leaf_tree_needs_empty_proof() {
+        let alice = Pubkey::new_unique();
+        let root = allowlist_leaf(&alice);
+        assert!(verify_allowlist_proof(&root, &alice, &[]));
+        assert!(!verify_allowlist_proof(&root, &Pubkey::new_unique(), &[]));
+    }
+
+    #[test]
+    fn verifies_every_member_of_a_four_leaf_tree() {
+        let members: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = members.iter().map(allowlist_leaf).collect();
+        let left = node(leaves[0], leaves[1]);
+        let right = node(leaves[2], leaves[3]);
+        let root = node(left, right);
+
+        assert!(verify_allowlist_proof(&root, &members[0], &[leaves[1], right]));
+        assert!(verify_allowlist_proof(&root, &members[1], &[leaves[0], right]));
+        assert!(verify_allowlist_proof(&root, &members[2], &[leaves[3], left]));
+        assert!(verify_allowlist_proof(&root, &members[3], &[leaves[2], left]));
+    }
+
+    #[test]
+    fn rejects_outsiders_and_tampered_proofs() {
+        let members: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = members.iter().map(allowlist_leaf).collect();
+        let root = node(leaves[0], leaves[1]);
+
+        assert!(!verify_allowlist_proof(&root, &Pubkey::new_unique(), &[leaves[1]]));
+        let mut tampered = leaves[1];
+        tampered[0] ^= 1;
+        assert!(!verify_allowlist_proof(&root, &members[0], &[tampered]));
+        // Proofs must be exactly the path to the root
+        assert!(!verify_allowlist_proof(&root, &members[0], &[leaves[1], leaves[0]]));
+    }
+
+    #[test]
+    fn open_contest_allows_everyone() {
+        let mut contest = crate::state::Contest::new(Pubkey::new_unique(), 0, 255, 0);
+        let alice = Pubkey::new_unique();
+        assert!(contest.is_allowed(&alice, &[]));
+
+        contest.allowlist_root = Some(allowlist_leaf(&Pubkey::new_unique()));
+        assert!(!contest.is_allowed(&alice, &[]));
+    }
+}
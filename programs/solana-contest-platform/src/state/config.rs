@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+/// Protocol-wide policy, controlled by an admin key
+///
+/// # Seeds: ["config"]
+/// Instructions read their limits from here instead of hard-coding them,
+/// so policy can be tuned without a redeploy. Values are bounded by the
+/// storage caps baked into account layouts (`MAX_JUDGES`, `MAX_URL_LEN`).
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub admin: Pubkey,                // 32 bytes - may update policy
+    pub pending_admin: Option<Pubkey>, // 1 + 32 bytes - proposed admin awaiting acceptance
+    pub min_prize_amount: u64,        // 8 bytes - minimum prize (and track prize) in lamports
+    pub max_judges: u8,               // 1 byte - judge panel limit (<= MAX_JUDGES)
+    pub reclaim_delay: i64,           // 8 bytes - reclaim time-lock, snapshotted onto new contests
+    pub max_url_len: u16,             // 2 bytes - submission and project link limit (<= MAX_URL_LEN)
+    pub platform_fee_bps: u16,        // 2 bytes - share of each prize sent to the treasury (snapshotted per contest)
+    pub relayer: Option<Pubkey>,      // 1 + 32 bytes - fee-payer relayer whose second signature gas pools reimburse
+    pub paused: bool,                 // 1 byte - protocol-wide circuit breaker
+    pub bump: u8,                     // 1 byte - PDA bump
+}
+
+/// Judges a contest account has room for
+pub const MAX_JUDGES: usize = 5;
+
+/// Bytes reserved for each URL in submission accounts
+pub const MAX_URL_LEN: usize = 200;
+
+/// Highest platform fee the admin can set (10%)
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000;
+
+/// Policy values passed to `initialize_config` and `update_config`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigParams {
+    pub min_prize_amount: u64,
+    pub max_judges: u8,
+    pub reclaim_delay: i64,
+    pub max_url_len: u16,
+    pub platform_fee_bps: u16,
+    pub relayer: Option<Pubkey>,
+}
+
+impl ConfigParams {
+    /// Rejects values the account layouts or escrow rent rules cannot honour
+    pub fn validate(&self) -> Result<()> {
+        // Escrows must stay rent-exempt after funding
+        require!(
+            self.min_prize_amount >= Rent::get()?.minimum_balance(0),
+            ErrorCode::InvalidConfig
+        );
+        require!(
+            self.max_judges != 0 && self.max_judges as usize <= MAX_JUDGES,
+            ErrorCode::InvalidConfig
+        );
+        require!(self.reclaim_delay > 0, ErrorCode::InvalidConfig);
+        require!(
+            self.max_url_len != 0 && self.max_url_len as usize <= MAX_URL_LEN,
+            ErrorCode::InvalidConfig
+        );
+        require!(
+            self.platform_fee_bps <= MAX_PLATFORM_FEE_BPS,
+            ErrorCode::InvalidConfig
+        );
+        Ok(())
+    }
+}
+
+impl Config {
+    /// Copies validated policy values onto the account
+    pub fn apply(&mut self, params: &ConfigParams) -> Result<()> {
+        params.validate()?;
+        self.min_prize_amount = params.min_prize_amount;
+        self.max_judges = params.max_judges;
+        self.reclaim_delay = params.reclaim_delay;
+        self.max_url_len = params.max_url_len;
+        self.platform_fee_bps = params.platform_fee_bps;
+        self.relayer = params.relayer;
+        Ok(())
+    }
+
+    /// Checks a prize (or track prize) against the configured minimum
+    pub fn check_prize(&self, amount: u64) -> Result<()> {
+        require!(amount >= self.min_prize_amount, ErrorCode::PrizeTooLow);
+        Ok(())
+    }
+
+    /// Checks a judge panel size against the configured limit
+    pub fn check_judges(&self, judges: &[Pubkey]) -> Result<()> {
+        require!(judges.len() <= self.max_judges as usize, ErrorCode::TooManyJudges);
+        require!(!judges.is_empty(), ErrorCode::NoJudges);
+        Ok(())
+    }
+
+    /// Checks a URL's length against the configured limit
+    pub fn check_url_len(&self, url: &str) -> Result<()> {
+        require!(url.len() <= self.max_url_len as usize, ErrorCode::UrlTooLong);
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+use crate::merkle::verify_allowlist_proof;
+use super::Submission;
 
 /// Contest account stores all contest metadata and state
 ///
@@ -11,11 +14,12 @@ use anchor_lang::prelude::*;
 /// - bool: 1 byte
 /// - u8: 1 byte
 ///
-/// Total: ~853 bytes (calculated automatically by InitSpace)
+/// Total: ~1840 bytes (calculated automatically by InitSpace)
 #[account]
 #[derive(InitSpace)]
 pub struct Contest {
-    pub creator: Pubkey,              // 32 bytes - who created the contest
+    pub version: u8,                  // 1 byte - layout version (see state::legacy)
+    pub creator: Pubkey,              // 32 bytes - who created the contest (fixed; part of PDA seeds)
     pub contest_id: u64,              // 8 bytes - unique identifier
     #[max_len(100)]
     pub title: String,                // 4 + 100 bytes - contest title
@@ -32,6 +36,324 @@ pub struct Contest {
     pub gas_sponsorship_enabled: bool, // 1 byte - gas subsidy enabled?
     pub funded: bool,                 // 1 byte - escrow funded?
     pub bump: u8,                     // 1 byte - PDA bump seed (stored for efficiency)
+    pub registration_deadline: i64,   // 8 bytes - end of registration window (0 = open entry)
+    pub max_participants: u32,        // 4 bytes - participant cap (0 = unlimited)
+    pub registered_count: u32,        // 4 bytes - number of registrations
+    pub entry_fee: u64,               // 8 bytes - fee per entry (0 = free)
+    pub entry_fee_mint: Option<Pubkey>, // 1 + 32 bytes - fee token mint (None = lamports)
+    pub entry_fee_prize_bps: u16,     // 2 bytes - share of fees added to the prize (basis points)
+    pub entry_fee_pool: u64,          // 8 bytes - fees held in escrow
+    pub winner: Option<Pubkey>,       // 1 + 32 bytes - set by distribute_prizes
+    pub allowlist_root: Option<[u8; 32]>, // 1 + 32 bytes - Merkle root of allowed participants
+    pub participation_gate: Option<ParticipationGate>, // 1 + 41 bytes - token/NFT holding requirement
+    pub reveal_period: i64,           // 8 bytes - sealed reveal window after deadline (0 = open)
+    #[max_len(5)]
+    pub tracks: Vec<Track>,           // 4 + (5 * 77) bytes - per-track prizes (empty = single prize)
+    pub late_period: i64,             // 8 bytes - grace window for late entries after deadline
+    pub late_penalty_votes: u8,       // 1 byte - extra approvals a late entry needs to win
+    pub milestone_count: u8,          // 1 byte - number of payout milestones (0 = lump sum)
+    pub milestone_total: u64,         // 8 bytes - sum of milestone amounts
+    pub milestones_released: u8,      // 1 byte - milestones paid out so far
+    pub revision: u32,                // 4 bytes - bumped on every update_contest
+    pub deadline_extension: i64,      // 8 bytes - total seconds added by extend_deadline
+    #[max_len(200)]
+    pub metadata_uri: Option<String>, // 1 + 4 + 200 bytes - off-chain brief (rules, criteria, assets)
+    pub metadata_hash: Option<[u8; 32]>, // 1 + 32 bytes - sha256 of the brief's exact bytes
+    pub settled_at: i64,              // 8 bytes - when the contest became Completed/Cancelled
+    pub open_accounts: u32,           // 4 bytes - child PDAs (registrations, entries, votes, milestones, roles) not yet closed
+    pub series: Option<Pubkey>,       // 1 + 32 bytes - series that opened this round (None = standalone)
+    pub submission_start: i64,        // 8 bytes - earliest submission time (0 = as soon as funded)
+    pub reclaim_delay: i64,           // 8 bytes - reclaim time-lock, snapshotted from config at creation
+    pub platform_fee_bps: u16,        // 2 bytes - treasury share of payouts, snapshotted from config at creation
+    pub paused: bool,                 // 1 byte - authority-controlled circuit breaker
+    pub paused_at: i64,               // 8 bytes - when the current pause began
+    pub paused_duration: i64,         // 8 bytes - total seconds spent paused (delays reclaim)
+    pub authority: Pubkey,            // 32 bytes - who manages the contest (starts as creator)
+    pub pending_authority: Option<Pubkey>, // 1 + 32 bytes - proposed authority awaiting acceptance
+}
+
+/// Maximum total time extend_deadline can add (30 days)
+pub const MAX_DEADLINE_EXTENSION: i64 = 30 * 24 * 60 * 60;
+
+/// Time after settlement during which accounts stay open for disputes (7 days)
+pub const DISPUTE_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+/// Base fee per transaction signature, reimbursed by the gas pool
+pub const SPONSORED_SIGNATURE_FEE: u64 = 5_000;
+
+/// Transaction fee a sponsored transaction reimburses: one signature, or two
+/// when the configured relayer pays on the participant's behalf. Any other
+/// second signer is the payer's own choice and is not reimbursed.
+pub fn sponsored_tx_fee(payer: &Pubkey, participant: &Pubkey, relayer: Option<Pubkey>) -> u64 {
+    if payer != participant && relayer == Some(*payer) {
+        2 * SPONSORED_SIGNATURE_FEE
+    } else {
+        SPONSORED_SIGNATURE_FEE
+    }
+}
+
+/// Maximum number of tracks per contest
+pub const MAX_TRACKS: usize = 5;
+
+/// A named submission track with its own prize inside the contest escrow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct Track {
+    #[max_len(32)]
+    pub name: String,                 // 4 + 32 bytes - e.g. "DeFi"
+    pub prize_amount: u64,            // 8 bytes - track prize in lamports
+    pub winner: Option<Pubkey>,       // 1 + 32 bytes - set when the track is distributed
+}
+
+/// Track definition passed to `configure_tracks`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TrackConfig {
+    pub name: String,
+    pub prize_amount: u64,
+}
+
+impl Contest {
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
+
+    /// A freshly created contest in Setup with every option off.
+    /// Callers fill in the title, prize, deadline and judges afterwards.
+    pub fn new(creator: Pubkey, contest_id: u64, bump: u8, now: i64) -> Self {
+        Self {
+            version: Self::VERSION,
+            creator,
+            contest_id,
+            title: String::new(),
+            description: String::new(),
+            prize_amount: 0,
+            submission_deadline: 0,
+            judges: Vec::new(),
+            approval_threshold: 0,
+            status: ContestStatus::Setup,
+            submission_count: 0,
+            created_at: now,
+            gas_sponsorship_enabled: false,
+            funded: false,
+            bump,
+            registration_deadline: 0,
+            max_participants: 0,
+            registered_count: 0,
+            entry_fee: 0,
+            entry_fee_mint: None,
+            entry_fee_prize_bps: 0,
+            entry_fee_pool: 0,
+            winner: None,
+            allowlist_root: None,
+            participation_gate: None,
+            reveal_period: 0,
+            tracks: Vec::new(),
+            late_period: 0,
+            late_penalty_votes: 0,
+            milestone_count: 0,
+            milestone_total: 0,
+            milestones_released: 0,
+            revision: 0,
+            deadline_extension: 0,
+            metadata_uri: None,
+            metadata_hash: None,
+            settled_at: 0,
+            open_accounts: 0,
+            series: None,
+            submission_start: 0,
+            reclaim_delay: 0,
+            platform_fee_bps: 0,
+            paused: false,
+            paused_at: 0,
+            paused_duration: 0,
+            authority: creator,
+            pending_authority: None,
+        }
+    }
+
+    /// Resets an `init`-ed account to `Contest::new`
+    pub fn initialize(&mut self, creator: Pubkey, contest_id: u64, bump: u8, now: i64) {
+        *self = Self::new(creator, contest_id, bump, now);
+    }
+
+    /// Whether participants must `register` before they can submit
+    pub fn registration_enabled(&self) -> bool {
+        self.registration_deadline != 0
+    }
+
+    /// When submit_entry starts accepting entries: the scheduled start, or
+    /// the end of the registration window if that is later
+    pub fn submissions_open_at(&self) -> i64 {
+        self.submission_start.max(self.registration_deadline)
+    }
+
+    /// Whether the contest charges an entry fee
+    pub fn has_entry_fee(&self) -> bool {
+        self.entry_fee != 0
+    }
+
+    /// Whether `participant` may enter, given an allowlist proof
+    pub fn is_allowed(&self, participant: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        match &self.allowlist_root {
+            Some(root) => verify_allowlist_proof(root, participant, proof),
+            None => true,
+        }
+    }
+
+    /// Whether submissions are sealed commitments revealed after the deadline
+    pub fn is_sealed(&self) -> bool {
+        self.reveal_period != 0
+    }
+
+    /// Last moment (exclusive) a late entry is accepted
+    pub fn late_deadline(&self) -> i64 {
+        self.submission_deadline.saturating_add(self.late_period)
+    }
+
+    /// End of the reveal window, which opens once late entries close.
+    /// Equals `late_deadline` for open contests; judging starts here.
+    pub fn reveal_deadline(&self) -> i64 {
+        self.late_deadline().saturating_add(self.reveal_period)
+    }
+
+    /// When reclaim_funds unlocks: `reclaim_delay` after judging opens, plus any
+    /// time spent paused. Because it is derived from the deadline, extensions
+    /// push it back by the same amount.
+    pub fn reclaim_available_at(&self) -> i64 {
+        self.reveal_deadline()
+            .saturating_add(self.reclaim_delay)
+            .saturating_add(self.paused_duration)
+    }
+
+    /// Platform fee withheld from a prize payout of `amount`
+    pub fn platform_fee(&self, amount: u64) -> u64 {
+        (amount as u128 * self.platform_fee_bps as u128 / 10_000) as u64
+    }
+
+    /// Approvals needed for an entry with the given lateness to win
+    pub fn required_votes(&self, lateness: i64) -> usize {
+        let threshold = self.approval_threshold as usize;
+        if lateness > 0 {
+            (threshold + self.late_penalty_votes as usize).min(self.judges.len())
+        } else {
+            threshold
+        }
+    }
+
+    /// Number of independently judged tracks (a plain contest is one track)
+    pub fn track_count(&self) -> usize {
+        self.tracks.len().max(1)
+    }
+
+    /// Whether the prize is paid out milestone by milestone
+    pub fn has_milestones(&self) -> bool {
+        self.milestone_count != 0
+    }
+
+    /// Whether anyone has registered or submitted, locking material fields
+    pub fn has_participants(&self) -> bool {
+        self.submission_count != 0 || self.registered_count != 0
+    }
+
+    /// Whether the contest is settled and its dispute window has passed,
+    /// so its accounts may be closed and their rent returned
+    pub fn is_closable(&self, now: i64) -> bool {
+        matches!(self.status, ContestStatus::Completed | ContestStatus::Cancelled)
+            && now >= self.settled_at.saturating_add(DISPUTE_WINDOW)
+    }
+
+    /// Pauses or resumes the contest. Paused time accumulates in
+    /// `paused_duration`, so a pause never runs down the reclaim clock.
+    pub fn set_paused(&mut self, paused: bool, now: i64) {
+        if paused && !self.paused {
+            self.paused_at = now;
+        } else if !paused && self.paused {
+            self.paused_duration = self
+                .paused_duration
+                .saturating_add(now.saturating_sub(self.paused_at));
+        }
+        self.paused = paused;
+    }
+
+    /// Marks the contest settled with the given final status
+    pub fn settle(&mut self, status: ContestStatus, now: i64) {
+        self.status = status;
+        self.settled_at = now;
+    }
+
+    /// Whether the authority may reclaim the escrow and cancel at `now`
+    ///
+    /// A bounty whose winner is known can still be reclaimed once the
+    /// time-lock passes; that is the winner's deadline for approved milestones.
+    pub fn check_reclaimable(&self, now: i64) -> Result<()> {
+        require!(
+            self.status != ContestStatus::Completed,
+            ErrorCode::ContestAlreadyCompleted
+        );
+        require!(
+            now >= self.reclaim_available_at(),
+            ErrorCode::ReclaimPeriodNotReached
+        );
+        Ok(())
+    }
+
+    /// Adds one entry's fee to the escrowed pool, returning the fee charged
+    pub fn collect_entry_fee(&mut self) -> Result<u64> {
+        self.entry_fee_pool = self
+            .entry_fee_pool
+            .checked_add(self.entry_fee)
+            .ok_or(ErrorCode::CalculationOverflow)?;
+        Ok(self.entry_fee)
+    }
+
+    /// Empties the fee pool of a completed contest, returning
+    /// (winner share, organizer share)
+    pub fn settle_entry_fees(&mut self) -> Result<(u64, u64)> {
+        require!(
+            self.status == ContestStatus::Completed,
+            ErrorCode::InvalidContestState
+        );
+        require!(self.entry_fee_pool > 0, ErrorCode::NoEntryFees);
+
+        let pool = self.entry_fee_pool;
+        let prize_share = (pool as u128)
+            .checked_mul(self.entry_fee_prize_bps as u128)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::CalculationOverflow)? as u64;
+        self.entry_fee_pool = 0;
+        Ok((prize_share, pool - prize_share))
+    }
+
+    /// Marks a submission's fee refunded after cancellation, returning the amount owed
+    pub fn refund_entry_fee(&mut self, submission: &mut Submission) -> Result<u64> {
+        require!(
+            self.status == ContestStatus::Cancelled,
+            ErrorCode::InvalidContestState
+        );
+        require!(
+            !submission.entry_fee_refunded,
+            ErrorCode::EntryFeeAlreadyRefunded
+        );
+
+        submission.entry_fee_refunded = true;
+        self.entry_fee_pool = self.entry_fee_pool.saturating_sub(submission.entry_fee_paid);
+        Ok(submission.entry_fee_paid)
+    }
+
+    /// Whether the participant cap (if any) has been reached for `count`
+    pub fn is_full(&self, count: u32) -> bool {
+        self.max_participants != 0 && count >= self.max_participants
+    }
+}
+
+/// Holding requirement checked when a participant enters
+///
+/// # Anchor Concept: Enums with data
+/// InitSpace sizes a data-carrying enum as 1 byte + its largest variant
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ParticipationGate {
+    /// Participant must hold at least `min_amount` of `mint`
+    TokenBalance { mint: Pubkey, min_amount: u64 },
+    /// Participant must hold an NFT verified in `collection`
+    NftCollection { collection: Pubkey },
 }
 
 /// Contest lifecycle states
@@ -39,7 +361,9 @@ pub struct Contest {
 /// # State Machine:
 /// Setup → (fund_contest) → Active → (distribute_prizes) → Completed
 ///   ↓
-/// Cancelled (via reclaim_funds after 30 days)
+/// Cancelled (via reclaim_funds after the reclaim delay)
+///
+/// Completed/Cancelled → (7-day dispute window) → close_* instructions
 ///
 /// # Anchor Concept: InitSpace for Enums
 /// InitSpace automatically calculates enum size as 1 byte (for up to 256 variants)
@@ -51,3 +375,189 @@ pub enum ContestStatus {
     Completed,  // Winner selected, prizes distributed
     Cancelled,  // Contest cancelled, funds reclaimed
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::SubmissionMetadata;
+
+    fn contest() -> Contest {
+        Contest::new(Pubkey::new_unique(), 0, 255, 1_000)
+    }
+
+    /// Contest charging `fee` per entry, with `entries` fees collected
+    fn fee_contest(fee: u64, prize_bps: u16, entries: u32) -> (Contest, Vec<Submission>) {
+        let mut contest = contest();
+        contest.status = ContestStatus::Active;
+        contest.entry_fee = fee;
+        contest.entry_fee_prize_bps = prize_bps;
+        let submissions = (0..entries)
+            .map(|_| {
+                let paid = contest.collect_entry_fee().unwrap();
+                Submission {
+                    version: Submission::VERSION,
+                    participant: Pubkey::new_unique(),
+                    contest: Pubkey::new_unique(),
+                    submission_url: String::new(),
+                    submitted_at: 0,
+                    last_modified: 0,
+                    bump: 255,
+                    entry_fee_paid: paid,
+                    entry_fee_refunded: false,
+                    commitment: None,
+                    revealed: true,
+                    track: 0,
+                    disqualify_votes: 0,
+                    disqualify_reason: [0; 32],
+                    disqualified: false,
+                    lateness: 0,
+                    rent_sponsored: 0,
+                    metadata: SubmissionMetadata::default(),
+                }
+            })
+            .collect();
+        (contest, submissions)
+    }
+
+    #[test]
+    fn late_entries_need_penalty_votes_capped_at_panel_size() {
+        let mut contest = contest();
+        contest.judges = (0..3).map(|_| Pubkey::new_unique()).collect();
+        contest.approval_threshold = 2;
+        contest.late_penalty_votes = 5;
+
+        assert_eq!(contest.required_votes(0), 2);
+        assert_eq!(contest.required_votes(1), 3);
+
+        contest.late_penalty_votes = 0;
+        assert_eq!(contest.required_votes(60), 2);
+    }
+
+    #[test]
+    fn closable_only_after_settlement_and_dispute_window() {
+        let mut contest = contest();
+        contest.status = ContestStatus::Active;
+        assert!(!contest.is_closable(i64::MAX));
+
+        contest.settle(ContestStatus::Completed, 5_000);
+        assert!(contest.status == ContestStatus::Completed);
+        assert_eq!(contest.settled_at, 5_000);
+        assert!(!contest.is_closable(5_000 + DISPUTE_WINDOW - 1));
+        assert!(contest.is_closable(5_000 + DISPUTE_WINDOW));
+
+        contest.settle(ContestStatus::Cancelled, 9_000);
+        assert!(!contest.is_closable(5_000 + DISPUTE_WINDOW));
+        assert!(contest.is_closable(9_000 + DISPUTE_WINDOW));
+    }
+
+    #[test]
+    fn only_the_configured_relayer_earns_a_second_signature_fee() {
+        let participant = Pubkey::new_unique();
+        let relayer = Pubkey::new_unique();
+        let stranger = Pubkey::new_unique();
+
+        assert_eq!(sponsored_tx_fee(&participant, &participant, None), SPONSORED_SIGNATURE_FEE);
+        assert_eq!(
+            sponsored_tx_fee(&participant, &participant, Some(participant)),
+            SPONSORED_SIGNATURE_FEE
+        );
+        assert_eq!(
+            sponsored_tx_fee(&relayer, &participant, Some(relayer)),
+            2 * SPONSORED_SIGNATURE_FEE
+        );
+        assert_eq!(sponsored_tx_fee(&stranger, &participant, Some(relayer)), SPONSORED_SIGNATURE_FEE);
+        assert_eq!(sponsored_tx_fee(&relayer, &participant, None), SPONSORED_SIGNATURE_FEE);
+    }
+
+    #[test]
+    fn paused_time_delays_reclaim() {
+        let mut contest = contest();
+        contest.submission_deadline = 10_000;
+        contest.reclaim_delay = 1_000;
+        assert_eq!(contest.reclaim_available_at(), 11_000);
+
+        contest.set_paused(true, 10_500);
+        // Pausing twice keeps the original start
+        contest.set_paused(true, 10_800);
+        contest.set_paused(false, 12_000);
+        assert_eq!(contest.paused_duration, 1_500);
+        assert_eq!(contest.reclaim_available_at(), 12_500);
+
+        // Resuming an unpaused contest adds nothing
+        contest.set_paused(false, 20_000);
+        assert_eq!(contest.reclaim_available_at(), 12_500);
+    }
+
+    #[test]
+    fn settling_splits_every_collected_fee_once() {
+        let (mut contest, _) = fee_contest(1_000_000, 7_000, 3);
+        assert_eq!(contest.entry_fee_pool, 3_000_000);
+
+        // Fees stay escrowed until a winner is paid
+        assert_eq!(
+            contest.settle_entry_fees().unwrap_err(),
+            ErrorCode::InvalidContestState.into()
+        );
+
+        contest.settle(ContestStatus::Completed, 5_000);
+        assert_eq!(contest.settle_entry_fees().unwrap(), (2_100_000, 900_000));
+        assert_eq!(contest.entry_fee_pool, 0);
+        assert_eq!(contest.settle_entry_fees().unwrap_err(), ErrorCode::NoEntryFees.into());
+    }
+
+    #[test]
+    fn settling_rounds_the_prize_share_down() {
+        let (mut contest, _) = fee_contest(7, 3_333, 1);
+        contest.settle(ContestStatus::Completed, 5_000);
+
+        let (prize, organizer) = contest.settle_entry_fees().unwrap();
+        assert_eq!((prize, organizer), (2, 5));
+    }
+
+    #[test]
+    fn fees_are_refunded_once_per_entry_after_cancellation() {
+        let (mut contest, mut entries) = fee_contest(1_000_000, 5_000, 2);
+        assert_eq!(
+            contest.refund_entry_fee(&mut entries[0]).unwrap_err(),
+            ErrorCode::InvalidContestState.into()
+        );
+
+        contest.settle(ContestStatus::Cancelled, 5_000);
+        assert_eq!(contest.refund_entry_fee(&mut entries[0]).unwrap(), 1_000_000);
+        assert!(entries[0].entry_fee_refunded);
+        assert_eq!(contest.entry_fee_pool, 1_000_000);
+
+        assert_eq!(
+            contest.refund_entry_fee(&mut entries[0]).unwrap_err(),
+            ErrorCode::EntryFeeAlreadyRefunded.into()
+        );
+        assert_eq!(contest.entry_fee_pool, 1_000_000);
+
+        assert_eq!(contest.refund_entry_fee(&mut entries[1]).unwrap(), 1_000_000);
+        assert_eq!(contest.entry_fee_pool, 0);
+    }
+
+    #[test]
+    fn unreleased_milestones_are_reclaimable_after_the_time_lock() {
+        let mut contest = contest();
+        contest.status = ContestStatus::Active;
+        contest.submission_deadline = 10_000;
+        contest.reclaim_delay = 1_000;
+        contest.milestone_count = 2;
+        contest.milestones_released = 1;
+        contest.winner = Some(Pubkey::new_unique());
+
+        assert_eq!(
+            contest.check_reclaimable(10_999).unwrap_err(),
+            ErrorCode::ReclaimPeriodNotReached.into()
+        );
+        assert!(contest.check_reclaimable(11_000).is_ok());
+
+        // Every milestone released completes the contest
+        contest.settle(ContestStatus::Completed, 12_000);
+        assert_eq!(
+            contest.check_reclaimable(20_000).unwrap_err(),
+            ErrorCode::ContestAlreadyCompleted.into()
+        );
+    }
+}
@@ -0,0 +1,378 @@
+//! Historical account layouts and version detection
+//!
+//! Version 0 is the original devnet layout, which had no version byte.
+//! Version 0 accounts are recognised by their fixed size; every later layout
+//! stores its version in the first byte after the discriminator. The
+//! `decode_*` helpers let clients read an account whatever its layout, and
+//! `migrate_contest` upgrades version 0 accounts in place.
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::errors::ErrorCode;
+use super::{Contest, ContestStatus, JudgeVoteAccount, Submission, SubmissionMetadata};
+
+/// Reclaim delay the version 0 program hard-coded (30 days)
+pub const LEGACY_RECLAIM_DELAY: i64 = 30 * 24 * 60 * 60;
+
+/// Contest layout version 0
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ContestV0 {
+    pub creator: Pubkey,
+    pub contest_id: u64,
+    #[max_len(100)]
+    pub title: String,
+    #[max_len(500)]
+    pub description: String,
+    pub prize_amount: u64,
+    pub submission_deadline: i64,
+    #[max_len(5)]
+    pub judges: Vec<Pubkey>,
+    pub approval_threshold: u8,
+    pub status: ContestStatus,
+    pub submission_count: u32,
+    pub created_at: i64,
+    pub gas_sponsorship_enabled: bool,
+    pub funded: bool,
+    pub bump: u8,
+}
+
+/// Submission layout version 0
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct SubmissionV0 {
+    pub participant: Pubkey,
+    pub contest: Pubkey,
+    #[max_len(200)]
+    pub submission_url: String,
+    pub submitted_at: i64,
+    pub last_modified: i64,
+    pub bump: u8,
+}
+
+/// Judge vote layout version 0
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct JudgeVoteAccountV0 {
+    pub judge: Pubkey,
+    pub contest: Pubkey,
+    pub winner: Pubkey,
+    pub voted_at: i64,
+    pub bump: u8,
+}
+
+impl ContestV0 {
+    /// Account size with discriminator; every version 0 contest has exactly this size
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Converts to the current layout. Options introduced later start off,
+    /// and settled contests start their dispute window at `now`.
+    pub fn upgrade(self, now: i64) -> Contest {
+        let mut contest = Contest::new(self.creator, self.contest_id, self.bump, self.created_at);
+        contest.title = self.title;
+        contest.description = self.description;
+        contest.prize_amount = self.prize_amount;
+        contest.submission_deadline = self.submission_deadline;
+        contest.judges = self.judges;
+        contest.approval_threshold = self.approval_threshold;
+        contest.submission_count = self.submission_count;
+        contest.gas_sponsorship_enabled = self.gas_sponsorship_enabled;
+        contest.funded = self.funded;
+        contest.reclaim_delay = LEGACY_RECLAIM_DELAY;
+        // Version 0 submissions are children that must be closed before the contest
+        contest.open_accounts = self.submission_count;
+        if matches!(self.status, ContestStatus::Completed | ContestStatus::Cancelled) {
+            contest.settle(self.status, now);
+        } else {
+            contest.status = self.status;
+        }
+        contest
+    }
+}
+
+impl SubmissionV0 {
+    /// Account size with discriminator; every version 0 submission has exactly this size
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Converts to the current layout as an on-time, revealed, fee-free entry
+    pub fn upgrade(self) -> Submission {
+        Submission {
+            version: Submission::VERSION,
+            participant: self.participant,
+            contest: self.contest,
+            submission_url: self.submission_url,
+            submitted_at: self.submitted_at,
+            last_modified: self.last_modified,
+            bump: self.bump,
+            entry_fee_paid: 0,
+            entry_fee_refunded: false,
+            commitment: None,
+            revealed: true,
+            track: 0,
+            disqualify_votes: 0,
+            disqualify_reason: [0; 32],
+            disqualified: false,
+            lateness: 0,
+            rent_sponsored: 0,
+            metadata: SubmissionMetadata::default(),
+        }
+    }
+}
+
+impl JudgeVoteAccountV0 {
+    /// Account size with discriminator; every version 0 vote has exactly this size
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Address a version 0 vote lives at; it had no track seed
+    pub fn address(contest: &Pubkey, judge: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vote", contest.as_ref(), judge.as_ref()], &crate::ID).0
+    }
+
+    /// Converts to the current layout as a vote on track 0
+    pub fn upgrade(self) -> JudgeVoteAccount {
+        JudgeVoteAccount {
+            version: JudgeVoteAccount::VERSION,
+            judge: self.judge,
+            contest: self.contest,
+            winner: self.winner,
+            voted_at: self.voted_at,
+            bump: self.bump,
+            track: 0,
+        }
+    }
+}
+
+/// A contest account decoded from any layout
+pub enum VersionedContest {
+    V0(ContestV0),
+    V1(Box<Contest>),
+}
+
+/// A submission account decoded from any layout
+pub enum VersionedSubmission {
+    V0(SubmissionV0),
+    V1(Box<Submission>),
+}
+
+/// A judge vote account decoded from any layout
+pub enum VersionedJudgeVote {
+    V0(JudgeVoteAccountV0),
+    V1(JudgeVoteAccount),
+}
+
+/// Reads the version byte of a post-version 0 layout. Version 0 is only ever
+/// inferred from the account size, never read from this byte.
+fn stored_version(data: &[u8]) -> Result<u8> {
+    match data.get(8) {
+        Some(&version) if version != 0 => Ok(version),
+        _ => err!(ErrorCode::UnsupportedAccountVersion),
+    }
+}
+
+/// Checks the discriminator and returns the layout version of a contest account
+pub fn contest_version(data: &[u8]) -> Result<u8> {
+    require!(
+        data.starts_with(Contest::DISCRIMINATOR),
+        ErrorCode::UnsupportedAccountVersion
+    );
+    if data.len() == ContestV0::LEN {
+        return Ok(0);
+    }
+    stored_version(data)
+}
+
+/// Checks the discriminator and returns the layout version of a submission.
+///
+/// Current submissions are sized to their content and can happen to be
+/// `SubmissionV0::LEN` long, so version 0 is confirmed by finding `contest`
+/// at its version 0 offset, which is impossible in later layouts.
+pub fn submission_version(data: &[u8], contest: &Pubkey) -> Result<u8> {
+    require!(
+        data.starts_with(Submission::DISCRIMINATOR),
+        ErrorCode::UnsupportedAccountVersion
+    );
+    if data.len() == SubmissionV0::LEN && data[40..72] == contest.to_bytes() {
+        return Ok(0);
+    }
+    stored_version(data)
+}
+
+/// Checks the discriminator and returns the layout version of a judge vote
+pub fn vote_version(data: &[u8]) -> Result<u8> {
+    require!(
+        data.starts_with(JudgeVoteAccount::DISCRIMINATOR),
+        ErrorCode::UnsupportedAccountVersion
+    );
+    if data.len() == JudgeVoteAccountV0::LEN {
+        return Ok(0);
+    }
+    stored_version(data)
+}
+
+/// Decodes a contest account of any known layout
+pub fn decode_contest(data: &[u8]) -> Result<VersionedContest> {
+    match contest_version(data)? {
+        0 => Ok(VersionedContest::V0(ContestV0::deserialize(&mut &data[8..])?)),
+        Contest::VERSION => Ok(VersionedContest::V1(Box::new(Contest::try_deserialize(&mut &data[..])?))),
+        _ => err!(ErrorCode::UnsupportedAccountVersion),
+    }
+}
+
+/// Decodes a submission account of any known layout
+pub fn decode_submission(data: &[u8], contest: &Pubkey) -> Result<VersionedSubmission> {
+    match submission_version(data, contest)? {
+        0 => Ok(VersionedSubmission::V0(SubmissionV0::deserialize(&mut &data[8..])?)),
+        Submission::VERSION => Ok(VersionedSubmission::V1(Box::new(Submission::try_deserialize(&mut &data[..])?))),
+        _ => err!(ErrorCode::UnsupportedAccountVersion),
+    }
+}
+
+/// Decodes a judge vote account of any known layout
+pub fn decode_vote(data: &[u8]) -> Result<VersionedJudgeVote> {
+    match vote_version(data)? {
+        0 => Ok(VersionedJudgeVote::V0(JudgeVoteAccountV0::deserialize(&mut &data[8..])?)),
+        JudgeVoteAccount::VERSION => Ok(VersionedJudgeVote::V1(JudgeVoteAccount::try_deserialize(&mut &data[..])?)),
+        _ => err!(ErrorCode::UnsupportedAccountVersion),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Serializes a version 0 account the way the old program stored it:
+    /// discriminator, fields, then zero padding up to the fixed size
+    fn legacy_account<T: AnchorSerialize>(discriminator: &[u8], value: &T, len: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        value.serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        data
+    }
+
+    fn contest_v0(status: ContestStatus) -> ContestV0 {
+        ContestV0 {
+            creator: Pubkey::new_unique(),
+            contest_id: 3,
+            title: "Old contest".to_string(),
+            description: "From devnet".to_string(),
+            prize_amount: 1_000_000,
+            submission_deadline: 50_000,
+            judges: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            approval_threshold: 2,
+            status,
+            submission_count: 4,
+            created_at: 10_000,
+            gas_sponsorship_enabled: true,
+            funded: true,
+            bump: 254,
+        }
+    }
+
+    fn submission_v0(contest: Pubkey) -> SubmissionV0 {
+        SubmissionV0 {
+            participant: Pubkey::new_unique(),
+            contest,
+            submission_url: "https://example.com/old".to_string(),
+            submitted_at: 20_000,
+            last_modified: 21_000,
+            bump: 253,
+        }
+    }
+
+    #[test]
+    fn version_0_contest_decodes_and_upgrades() {
+        let legacy = contest_v0(ContestStatus::Active);
+        let data = legacy_account(Contest::DISCRIMINATOR, &legacy, ContestV0::LEN);
+        assert_eq!(contest_version(&data).unwrap(), 0);
+
+        let VersionedContest::V0(decoded) = decode_contest(&data).unwrap() else {
+            panic!("expected a version 0 contest");
+        };
+        let contest = decoded.upgrade(60_000);
+        assert_eq!(contest.version, Contest::VERSION);
+        assert_eq!(contest.creator, legacy.creator);
+        assert_eq!(contest.authority, legacy.creator);
+        assert_eq!(contest.title, legacy.title);
+        assert_eq!(contest.judges, legacy.judges);
+        assert_eq!(contest.reclaim_delay, LEGACY_RECLAIM_DELAY);
+        assert_eq!(contest.open_accounts, legacy.submission_count);
+        assert!(contest.status == ContestStatus::Active);
+    }
+
+    #[test]
+    fn settled_version_0_contest_starts_its_dispute_window_at_migration() {
+        let legacy = contest_v0(ContestStatus::Completed);
+        let contest = legacy.upgrade(60_000);
+        assert!(contest.status == ContestStatus::Completed);
+        assert!(!contest.is_closable(60_000));
+    }
+
+    #[test]
+    fn current_contest_decodes_as_version_1() {
+        let mut data = Vec::new();
+        Contest::new(Pubkey::new_unique(), 0, 255, 1_000)
+            .try_serialize(&mut data)
+            .unwrap();
+        data.resize(8 + Contest::INIT_SPACE, 0);
+
+        assert_eq!(contest_version(&data).unwrap(), Contest::VERSION);
+        assert!(matches!(decode_contest(&data).unwrap(), VersionedContest::V1(_)));
+    }
+
+    #[test]
+    fn rejects_foreign_discriminators_and_unknown_versions() {
+        let legacy = contest_v0(ContestStatus::Active);
+        let data = legacy_account(Submission::DISCRIMINATOR, &legacy, ContestV0::LEN);
+        assert!(decode_contest(&data).is_err());
+
+        let mut data = Contest::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[9; 100]);
+        assert!(decode_contest(&data).is_err());
+    }
+
+    #[test]
+    fn version_0_submission_needs_contest_at_its_offset() {
+        let contest = Pubkey::new_unique();
+        let legacy = submission_v0(contest);
+        let data = legacy_account(Submission::DISCRIMINATOR, &legacy, SubmissionV0::LEN);
+
+        let VersionedSubmission::V0(decoded) = decode_submission(&data, &contest).unwrap() else {
+            panic!("expected a version 0 submission");
+        };
+        let submission = decoded.upgrade();
+        assert_eq!(submission.participant, legacy.participant);
+        assert_eq!(submission.submission_url, legacy.submission_url);
+        assert!(submission.revealed);
+
+        // Same size, different contest: read as a current layout instead
+        assert!(!matches!(
+            decode_submission(&data, &Pubkey::new_unique()),
+            Ok(VersionedSubmission::V0(_))
+        ));
+    }
+
+    #[test]
+    fn version_0_vote_decodes_to_track_0_at_its_pre_track_address() {
+        let contest = Pubkey::new_unique();
+        let judge = Pubkey::new_unique();
+        let legacy = JudgeVoteAccountV0 {
+            judge,
+            contest,
+            winner: Pubkey::new_unique(),
+            voted_at: 70_000,
+            bump: 252,
+        };
+        let data = legacy_account(JudgeVoteAccount::DISCRIMINATOR, &legacy, JudgeVoteAccountV0::LEN);
+
+        let VersionedJudgeVote::V0(decoded) = decode_vote(&data).unwrap() else {
+            panic!("expected a version 0 vote");
+        };
+        let vote = decoded.upgrade();
+        assert_eq!(vote.track, 0);
+        assert_eq!(vote.winner, legacy.winner);
+
+        let tracked = Pubkey::find_program_address(
+            &[b"vote", contest.as_ref(), judge.as_ref(), &[0]],
+            &crate::ID,
+        )
+        .0;
+        assert_ne!(JudgeVoteAccountV0::address(&contest, &judge), tracked);
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Milestone account splits a bounty prize into separately approved payouts
+///
+/// # Milestone Pattern:
+/// - Creator defines milestones before funding; amounts sum to the prize
+/// - distribute_prizes picks the winner but releases nothing
+/// - Winner posts a proof hash per milestone
+/// - Judges approve each proof; at threshold that milestone's amount is released
+#[account]
+#[derive(InitSpace)]
+pub struct Milestone {
+    pub contest: Pubkey,              // 32 bytes - which contest
+    pub index: u8,                    // 1 byte - position in the contest's milestones
+    pub description_hash: [u8; 32],   // 32 bytes - hash of the milestone spec
+    pub amount: u64,                  // 8 bytes - lamports released on approval
+    pub proof_hash: Option<[u8; 32]>, // 1 + 32 bytes - hash of the winner's proof
+    pub approvals: u8,                // 1 byte - bitmask of approving judge indices
+    pub released: bool,               // 1 byte - amount paid out?
+    pub bump: u8,                     // 1 byte - PDA bump
+}
+
+/// Maximum number of milestones per contest
+pub const MAX_MILESTONES: u8 = 10;
@@ -1,7 +1,21 @@
+pub mod config;
 pub mod contest;
+pub mod legacy;
+pub mod milestone;
+pub mod registration;
+pub mod registry;
+pub mod role;
+pub mod series;
 pub mod submission;
 pub mod vote;
 
+pub use config::*;
 pub use contest::*;
+pub use legacy::*;
+pub use milestone::*;
+pub use registration::*;
+pub use registry::*;
+pub use role::*;
+pub use series::*;
 pub use submission::*;
 pub use vote::*;
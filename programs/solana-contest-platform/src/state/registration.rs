@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// Registration account marks a wallet as admitted to a contest
+///
+/// Only created when the contest has a registration window. Its
+/// existence is what `submit_entry` checks, so it carries no flags.
+#[account]
+#[derive(InitSpace)]
+pub struct Registration {
+    pub participant: Pubkey,          // 32 bytes - who registered
+    pub contest: Pubkey,              // 32 bytes - which contest
+    pub registered_at: i64,           // 8 bytes - registration time
+    pub bump: u8,                     // 1 byte - PDA bump
+}
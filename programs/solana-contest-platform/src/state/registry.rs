@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+/// Global registry singleton that hands out contest IDs
+///
+/// # Seeds: ["registry"]
+/// `create_contest` uses `contest_count` as the new contest's ID and
+/// increments it, so IDs are unique across all creators and never reused.
+#[account]
+#[derive(InitSpace)]
+pub struct Registry {
+    pub contest_count: u64,           // 8 bytes - next contest ID
+    pub bump: u8,                     // 1 byte - PDA bump
+}
+
+/// Per-creator index header
+///
+/// # Seeds: ["creator_index", creator]
+/// Contest `n` of a creator lives on page `n / CREATOR_INDEX_PAGE_SIZE`,
+/// so clients can walk pages 0..=last without scanning program accounts.
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorIndex {
+    pub creator: Pubkey,              // 32 bytes - whose contests are indexed
+    pub contest_count: u32,           // 4 bytes - contests created by this creator
+    pub bump: u8,                     // 1 byte - PDA bump
+}
+
+/// One page of a creator's contest IDs, in creation order
+///
+/// # Seeds: ["creator_index", creator, page.to_le_bytes()]
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorIndexPage {
+    pub creator: Pubkey,              // 32 bytes - whose contests are listed
+    pub page: u32,                    // 4 bytes - page number
+    #[max_len(32)]
+    pub contest_ids: Vec<u64>,        // 4 + (32 * 8) bytes - contest IDs on this page
+    pub bump: u8,                     // 1 byte - PDA bump
+}
+
+/// Contest IDs per creator index page
+pub const CREATOR_INDEX_PAGE_SIZE: u32 = 32;
+
+impl Registry {
+    /// Consumes the current contest ID, moving the counter to the next one
+    pub fn advance(&mut self) -> Result<()> {
+        self.contest_count = self
+            .contest_count
+            .checked_add(1)
+            .ok_or(ErrorCode::CalculationOverflow)?;
+        Ok(())
+    }
+}
+
+impl CreatorIndex {
+    /// Page that the creator's next contest is recorded on
+    pub fn next_page(&self) -> u32 {
+        self.contest_count / CREATOR_INDEX_PAGE_SIZE
+    }
+
+    /// Appends `contest_id` to `page`, filling in the index and page headers
+    /// when `init_if_needed` has just created them
+    pub fn record(
+        &mut self,
+        page: &mut CreatorIndexPage,
+        creator: Pubkey,
+        contest_id: u64,
+        index_bump: u8,
+        page_bump: u8,
+    ) -> Result<()> {
+        if self.creator == Pubkey::default() {
+            self.creator = creator;
+            self.bump = index_bump;
+        }
+        if page.contest_ids.is_empty() {
+            page.creator = creator;
+            page.page = self.next_page();
+            page.bump = page_bump;
+        }
+        page.contest_ids.push(contest_id);
+        self.contest_count = self
+            .contest_count
+            .checked_add(1)
+            .ok_or(ErrorCode::CalculationOverflow)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> CreatorIndex {
+        CreatorIndex {
+            creator: Pubkey::default(),
+            contest_count: 0,
+            bump: 0,
+        }
+    }
+
+    fn empty_page() -> CreatorIndexPage {
+        CreatorIndexPage {
+            creator: Pubkey::default(),
+            page: 0,
+            contest_ids: Vec::new(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn registry_hands_out_sequential_ids() {
+        let mut registry = Registry { contest_count: 0, bump: 0 };
+        for expected in 1..=3 {
+            registry.advance().unwrap();
+            assert_eq!(registry.contest_count, expected);
+        }
+
+        registry.contest_count = u64::MAX;
+        assert!(registry.advance().is_err());
+    }
+
+    #[test]
+    fn first_record_fills_in_headers() {
+        let creator = Pubkey::new_unique();
+        let mut index = index();
+        let mut page = empty_page();
+
+        index.record(&mut page, creator, 7, 254, 253).unwrap();
+
+        assert_eq!(index.creator, creator);
+        assert_eq!(index.bump, 254);
+        assert_eq!(index.contest_count, 1);
+        assert_eq!(page.creator, creator);
+        assert_eq!(page.page, 0);
+        assert_eq!(page.bump, 253);
+        assert_eq!(page.contest_ids, vec![7]);
+    }
+
+    #[test]
+    fn pages_roll_over_every_page_size_contests() {
+        let creator = Pubkey::new_unique();
+        let mut index = index();
+        let mut pages = vec![empty_page()];
+
+        for id in 0..(CREATOR_INDEX_PAGE_SIZE as u64 * 2 + 1) {
+            let page_number = index.next_page() as usize;
+            if page_number == pages.len() {
+                pages.push(empty_page());
+            }
+            index.record(&mut pages[page_number], creator, id, 1, 1).unwrap();
+        }
+
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[0].contest_ids.len(), CREATOR_INDEX_PAGE_SIZE as usize);
+        assert_eq!(pages[1].page, 1);
+        assert_eq!(pages[1].contest_ids[0], CREATOR_INDEX_PAGE_SIZE as u64);
+        assert_eq!(pages[2].page, 2);
+        assert_eq!(pages[2].contest_ids, vec![CREATOR_INDEX_PAGE_SIZE as u64 * 2]);
+        assert_eq!(index.next_page(), 2);
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// Permissions the contest authority has delegated to an operator
+///
+/// # Seeds: ["role", contest, delegate]
+/// Operators can run day-to-day tasks without the authority key. Anything
+/// that moves prize funds or ownership stays authority-only.
+#[account]
+#[derive(InitSpace)]
+pub struct ContestRole {
+    pub contest: Pubkey,              // 32 bytes - which contest
+    pub delegate: Pubkey,             // 32 bytes - operator the role is granted to
+    pub permissions: u8,              // 1 byte - bitmask of ContestRole::* permissions
+    pub granted_at: i64,              // 8 bytes - last grant time
+    pub bump: u8,                     // 1 byte - PDA bump
+}
+
+impl ContestRole {
+    /// May call `extend_deadline`
+    pub const EXTEND_DEADLINE: u8 = 1 << 0;
+    /// May disqualify submissions without a judge quorum
+    pub const DISQUALIFY: u8 = 1 << 1;
+    /// May call `enable_gas_sponsorship` (funding it from their own wallet)
+    pub const GAS_SPONSORSHIP: u8 = 1 << 2;
+    /// May call `set_contest_pause`
+    pub const PAUSE: u8 = 1 << 3;
+    /// May call `update_contest`
+    pub const UPDATE_CONTEST: u8 = 1 << 4;
+    /// May call `set_allowlist_root`
+    pub const MANAGE_ALLOWLIST: u8 = 1 << 5;
+    /// Every defined permission
+    pub const ALL: u8 = (1 << 6) - 1;
+
+    /// Whether this role includes `permission`
+    pub fn has(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
+}
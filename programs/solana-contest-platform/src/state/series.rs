@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+/// Recurring contest series with a template and a prefunded budget
+///
+/// # Seeds: ["series", creator, series_id]
+/// The budget lives in a separate system-owned vault PDA
+/// (["series_vault", series]) so it can pay escrows and rent directly.
+///
+/// Every round is a normal `Contest` owned by the series creator. Rounds
+/// open already funded and Active, so Setup-only options do not apply.
+#[account]
+#[derive(InitSpace)]
+pub struct Series {
+    pub creator: Pubkey,              // 32 bytes - who owns the series and its rounds
+    pub series_id: u64,               // 8 bytes - creator-chosen identifier
+    #[max_len(100)]
+    pub title: String,                // 4 + 100 bytes - title given to every round
+    #[max_len(500)]
+    pub description: String,          // 4 + 500 bytes - description given to every round
+    pub prize_amount: u64,            // 8 bytes - prize each round is funded with
+    #[max_len(5)]
+    pub judges: Vec<Pubkey>,          // 4 + (5 * 32) bytes - judge panel for every round
+    pub approval_threshold: u8,       // 1 byte - votes needed per round
+    pub round_duration: i64,          // 8 bytes - seconds from opening to submission deadline
+    pub round_interval: i64,          // 8 bytes - seconds between scheduled round openings
+    pub next_round_at: i64,           // 8 bytes - earliest time the next round may open
+    pub max_rounds: u32,              // 4 bytes - cap on rounds (0 = unlimited)
+    pub rounds_opened: u32,           // 4 bytes - rounds opened so far
+    pub current_contest: Option<Pubkey>, // 1 + 32 bytes - most recently opened round
+    pub bump: u8,                     // 1 byte - PDA bump
+    pub vault_bump: u8,               // 1 byte - vault PDA bump
+}
+
+/// Series schedule and template passed to `create_series`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SeriesTemplate {
+    pub title: String,
+    pub description: String,
+    pub prize_amount: u64,
+    pub judges: Vec<Pubkey>,
+    pub approval_threshold: u8,
+    pub round_duration: i64,
+    pub round_interval: i64,
+    pub first_round_at: i64,
+    pub max_rounds: u32,
+}
+
+impl Series {
+    /// Whether the series has opened all the rounds it is allowed to
+    pub fn is_finished(&self) -> bool {
+        self.max_rounds != 0 && self.rounds_opened >= self.max_rounds
+    }
+}
@@ -1,9 +1,15 @@
 use anchor_lang::prelude::*;
 
 /// Submission account stores participant entry data
+///
+/// # Space Calculation:
+/// INIT_SPACE is the size with every string at its max length. Accounts are
+/// instead created and reallocated to fit the strings actually stored, so
+/// participants only pay rent for what they use (see `Submission::space`).
 #[account]
 #[derive(InitSpace)]
 pub struct Submission {
+    pub version: u8,                  // 1 byte - layout version (see state::legacy)
     pub participant: Pubkey,          // 32 bytes - who submitted
     pub contest: Pubkey,              // 32 bytes - which contest
     #[max_len(200)]
@@ -11,4 +17,181 @@ pub struct Submission {
     pub submitted_at: i64,            // 8 bytes - submission time
     pub last_modified: i64,           // 8 bytes - last update time
     pub bump: u8,                     // 1 byte - PDA bump
+    pub entry_fee_paid: u64,          // 8 bytes - entry fee held in escrow
+    pub entry_fee_refunded: bool,     // 1 byte - fee returned after cancellation?
+    pub commitment: Option<[u8; 32]>, // 1 + 32 bytes - sha256(url || salt) for sealed contests
+    pub revealed: bool,               // 1 byte - URL public? (always true for open contests)
+    pub track: u8,                    // 1 byte - track index (0 for single-prize contests)
+    pub disqualify_votes: u8,         // 1 byte - bitmask of judge indices voting to disqualify
+    pub disqualify_reason: [u8; 32],  // 32 bytes - hash of the cited ruling
+    pub disqualified: bool,           // 1 byte - excluded from winning?
+    pub lateness: i64,                // 8 bytes - seconds past the deadline (0 = on time)
+    pub rent_sponsored: u64,          // 8 bytes - rent the gas pool paid, returned to it on close
+    pub metadata: SubmissionMetadata, // 5 * 4 + up to 944 bytes - optional project details
+}
+
+impl Submission {
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
+
+    /// Bytes INIT_SPACE reserves for string contents at their max length
+    const MAX_STRING_BYTES: usize = 200 + SubmissionMetadata::MAX_STRING_BYTES;
+
+    /// Account size (with discriminator) for the given URL length and metadata
+    pub fn space(url_len: usize, metadata: &SubmissionMetadata) -> usize {
+        8 + Self::INIT_SPACE - Self::MAX_STRING_BYTES + url_len + metadata.used_bytes()
+    }
+
+    /// Whether an `update_submission` call with these arguments changes anything
+    pub fn update_changes(
+        &self,
+        new_url: &str,
+        new_commitment: &Option<[u8; 32]>,
+        metadata: &Option<SubmissionMetadata>,
+    ) -> bool {
+        (new_commitment.is_some() && *new_commitment != self.commitment)
+            || (!new_url.is_empty() && new_url != self.submission_url)
+            || metadata.as_ref().is_some_and(|metadata| *metadata != self.metadata)
+    }
+
+    /// Account size after `update_submission` applies its arguments
+    pub fn space_after_update(&self, new_url: &str, metadata: &Option<SubmissionMetadata>) -> usize {
+        let url_len = if new_url.is_empty() {
+            self.submission_url.len()
+        } else {
+            new_url.len()
+        };
+        Self::space(url_len, metadata.as_ref().unwrap_or(&self.metadata))
+    }
+}
+
+/// Optional project details attached to a submission
+///
+/// Empty strings mean "not provided" and cost no rent beyond their
+/// 4-byte length prefix.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, InitSpace)]
+pub struct SubmissionMetadata {
+    #[max_len(64)]
+    pub title: String,                // 4 + 64 bytes - project title
+    #[max_len(200)]
+    pub repo_url: String,             // 4 + 200 bytes - source repository
+    #[max_len(200)]
+    pub demo_url: String,             // 4 + 200 bytes - live demo
+    #[max_len(200)]
+    pub video_url: String,            // 4 + 200 bytes - walkthrough video
+    #[max_len(280)]
+    pub description: String,          // 4 + 280 bytes - short description
+}
+
+impl SubmissionMetadata {
+    const MAX_STRING_BYTES: usize = 64 + 200 + 200 + 200 + 280;
+
+    /// Bytes of string content actually stored
+    pub fn used_bytes(&self) -> usize {
+        self.title.len()
+            + self.repo_url.len()
+            + self.demo_url.len()
+            + self.video_url.len()
+            + self.description.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(url: &str, metadata: SubmissionMetadata) -> Submission {
+        Submission {
+            version: Submission::VERSION,
+            participant: Pubkey::new_unique(),
+            contest: Pubkey::new_unique(),
+            submission_url: url.to_string(),
+            submitted_at: 0,
+            last_modified: 0,
+            bump: 255,
+            entry_fee_paid: 0,
+            entry_fee_refunded: false,
+            commitment: None,
+            revealed: true,
+            track: 0,
+            disqualify_votes: 0,
+            disqualify_reason: [0; 32],
+            disqualified: false,
+            lateness: 0,
+            rent_sponsored: 0,
+            metadata,
+        }
+    }
+
+    fn serialized_len(submission: &Submission) -> usize {
+        let mut data = Vec::new();
+        submission.try_serialize(&mut data).unwrap();
+        data.len()
+    }
+
+    fn metadata() -> SubmissionMetadata {
+        SubmissionMetadata {
+            title: "SolArena".to_string(),
+            repo_url: "https://github.com/example/solarena".to_string(),
+            demo_url: String::new(),
+            video_url: "https://example.com/video".to_string(),
+            description: "A contest platform".to_string(),
+        }
+    }
+
+    #[test]
+    fn space_matches_serialized_size_for_any_url_length() {
+        for url_len in [0, 1, 8, 57, 200] {
+            let url = "a".repeat(url_len);
+            for metadata in [SubmissionMetadata::default(), metadata()] {
+                let mut entry = submission(&url, metadata);
+                // Space reserves room for a commitment, which an open entry leaves unset
+                assert_eq!(Submission::space(url_len, &entry.metadata), serialized_len(&entry) + 32);
+                entry.commitment = Some([7; 32]);
+                assert_eq!(Submission::space(url_len, &entry.metadata), serialized_len(&entry));
+            }
+        }
+    }
+
+    #[test]
+    fn space_at_max_lengths_is_init_space() {
+        let full = SubmissionMetadata {
+            title: "t".repeat(64),
+            repo_url: "r".repeat(200),
+            demo_url: "d".repeat(200),
+            video_url: "v".repeat(200),
+            description: "x".repeat(280),
+        };
+        assert_eq!(Submission::space(200, &full), 8 + Submission::INIT_SPACE);
+    }
+
+    #[test]
+    fn no_op_updates_change_nothing() {
+        let entry = submission("https://example.com/a", metadata());
+
+        assert!(!entry.update_changes("", &None, &None));
+        assert!(!entry.update_changes("https://example.com/a", &None, &Some(metadata())));
+        assert!(entry.update_changes("https://example.com/b", &None, &None));
+        assert!(entry.update_changes("", &Some([7; 32]), &None));
+        assert!(entry.update_changes("", &None, &Some(SubmissionMetadata::default())));
+    }
+
+    #[test]
+    fn space_after_update_keeps_unchanged_fields() {
+        let entry = submission("https://example.com/a", metadata());
+        let current = Submission::space(entry.submission_url.len(), &entry.metadata);
+
+        // Empty URL and no metadata leave the size as is
+        assert_eq!(entry.space_after_update("", &None), current);
+        // A longer URL grows the account by the difference
+        assert_eq!(
+            entry.space_after_update("https://example.com/abc", &None),
+            current + 2
+        );
+        // Clearing the metadata shrinks it to the bare URL
+        assert_eq!(
+            entry.space_after_update("", &Some(SubmissionMetadata::default())),
+            Submission::space(entry.submission_url.len(), &SubmissionMetadata::default())
+        );
+    }
 }
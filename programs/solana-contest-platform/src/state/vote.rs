@@ -7,12 +7,20 @@ use anchor_lang::prelude::*;
 /// - Each judge creates their own vote PDA
 /// - distribute_prizes counts matching votes
 /// - Threshold determines consensus
+/// - Track contests get one vote PDA per judge per track
 #[account]
 #[derive(InitSpace)]
 pub struct JudgeVoteAccount {
+    pub version: u8,                  // 1 byte - layout version (see state::legacy)
     pub judge: Pubkey,                // 32 bytes - who voted
     pub contest: Pubkey,              // 32 bytes - which contest
     pub winner: Pubkey,               // 32 bytes - their winner choice
     pub voted_at: i64,                // 8 bytes - vote timestamp
     pub bump: u8,                     // 1 byte - PDA bump
+    pub track: u8,                    // 1 byte - track being judged
+}
+
+impl JudgeVoteAccount {
+    /// Current account layout version
+    pub const VERSION: u8 = 1;
 }
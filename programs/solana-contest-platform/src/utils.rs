@@ -0,0 +1,138 @@
+//! Shared helpers for moving funds out of program-owned PDAs and for
+//! rewriting accounts in place
+//!
+//! # Anchor Concepts:
+//! - Escrow and gas pool PDAs are plain system accounts (no data), so
+//!   only the System Program can debit them
+//! - The program authorizes the debit by signing with the PDA seeds
+//!   through `CpiContext::new_with_signer`
+//! - Accounts owned by the program can be resized and re-serialized
+//!   directly, as long as they stay rent-exempt
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked};
+use crate::instruction::{SubmitEntry, UpdateSubmission};
+
+/// Transfers lamports out of a system-owned PDA
+pub fn transfer_lamports_from_pda<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_context = CpiContext::new_with_signer(
+        system_program.clone(),
+        Transfer {
+            from: from.clone(),
+            to: to.clone(),
+        },
+        signer_seeds,
+    );
+    transfer(cpi_context, amount)
+}
+
+/// Pays `amount` out of a contest's gas pool while keeping the pool rent-exempt.
+/// Returns `false`, paying nothing, when the pool cannot cover it.
+pub fn pay_from_gas_pool<'info>(
+    gas_pool: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    contest: &Pubkey,
+    bump: u8,
+    amount: u64,
+) -> Result<bool> {
+    let spendable = gas_pool
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    if amount > spendable {
+        return Ok(false);
+    }
+    let seeds: &[&[u8]] = &[b"gas_pool", contest.as_ref(), &[bump]];
+    transfer_lamports_from_pda(gas_pool, to, system_program, &[seeds], amount)?;
+    Ok(true)
+}
+
+/// Whether the executing instruction may reimburse the transaction fee. It must
+/// be a top-level SolArena instruction with no earlier `submit_entry` or
+/// `update_submission` in the transaction, so each transaction is repaid once.
+pub fn first_sponsored_instruction(instructions: &AccountInfo) -> Result<bool> {
+    let current = load_current_index_checked(instructions)?;
+    if load_instruction_at_checked(current as usize, instructions)?.program_id != crate::ID {
+        return Ok(false);
+    }
+    for index in 0..current as usize {
+        let instruction = load_instruction_at_checked(index, instructions)?;
+        let data = instruction.data.as_slice();
+        if instruction.program_id == crate::ID
+            && (data.starts_with(SubmitEntry::DISCRIMINATOR)
+                || data.starts_with(UpdateSubmission::DISCRIMINATOR))
+        {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Transfers SPL tokens out of a token account owned by a PDA
+pub fn transfer_tokens_from_pda<'info>(
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_context = CpiContext::new_with_signer(
+        token_program.clone(),
+        TransferChecked {
+            from: from.to_account_info(),
+            mint: mint.to_account_info(),
+            to: to.to_account_info(),
+            authority: authority.clone(),
+        },
+        signer_seeds,
+    );
+    transfer_checked(cpi_context, amount, mint.decimals)
+}
+
+/// Re-serializes `value` into a program-owned account at `new_len` bytes,
+/// topping up rent from `payer` when the account grows
+pub fn rewrite_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    value: &T,
+    new_len: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        let cpi_context = CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+        );
+        transfer(cpi_context, shortfall)?;
+    }
+    if account.data_len() != new_len {
+        account.resize(new_len)?;
+    }
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    value.try_serialize(&mut &mut data[..])
+}
@@ -0,0 +1,53 @@
+import { expect } from "chai";
+import {
+  allowlistLeaf,
+  allowlistNode,
+  createContest,
+  errorCode,
+  fundContest,
+  fundedKeypair,
+  program,
+  submissionPda,
+  submitEntry,
+} from "./helpers";
+
+describe("allowlist", () => {
+  it("accepts an allowlisted participant with a valid proof", async () => {
+    const alice = await fundedKeypair();
+    const bob = await fundedKeypair();
+    const aliceLeaf = allowlistLeaf(alice.publicKey);
+    const bobLeaf = allowlistLeaf(bob.publicKey);
+    const root = allowlistNode(aliceLeaf, bobLeaf);
+
+    const t = await createContest({ allowlistRoot: [...root] });
+    await fundContest(t);
+    await submitEntry(t.contest, alice, "https://example.com/alice", {
+      allowlistProof: [[...bobLeaf]],
+    }).rpc();
+
+    const submission = await program.account.submission.fetch(
+      submissionPda(t.contest, alice.publicKey)
+    );
+    expect(submission.participant.toBase58()).to.equal(
+      alice.publicKey.toBase58()
+    );
+  });
+
+  it("rejects a participant outside the allowlist", async () => {
+    const alice = await fundedKeypair();
+    const mallory = await fundedKeypair();
+    const root = allowlistNode(
+      allowlistLeaf(alice.publicKey),
+      allowlistLeaf((await fundedKeypair(1)).publicKey)
+    );
+
+    const t = await createContest({ allowlistRoot: [...root] });
+    await fundContest(t);
+    const code = await errorCode(
+      submitEntry(t.contest, mallory, "https://example.com/mallory", {
+        allowlistProof: [[...allowlistLeaf(alice.publicKey)]],
+      }).rpc()
+    );
+    expect(code).to.equal("NotOnAllowlist");
+  });
+});
@@ -0,0 +1,269 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  balance,
+  createContest,
+  distributePrizes,
+  errorCode,
+  fundContest,
+  fundedKeypair,
+  judgeVote,
+  program,
+  reclaimFunds,
+  submitEntry,
+  submissionPda,
+  TestContest,
+  waitUntil,
+  withConfig,
+} from "./helpers";
+import {
+  createMint,
+  createTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+  tokenBalance,
+} from "./token";
+
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+describe("entry fees", () => {
+  const configureEntryFee = (
+    t: TestContest,
+    fee: number,
+    mint: anchor.web3.PublicKey | null,
+    prizeShareBps: number
+  ) =>
+    program.methods
+      .configureEntryFee(new BN(fee), mint, prizeShareBps)
+      .accountsPartial({ contest: t.contest, authority: t.creator.publicKey })
+      .signers([t.creator])
+      .rpc();
+
+  const refund = (
+    t: TestContest,
+    entrant: anchor.web3.Keypair,
+    tokens: {
+      feeMint: anchor.web3.PublicKey;
+      escrowFeeAccount: anchor.web3.PublicKey;
+      participantFeeAccount: anchor.web3.PublicKey;
+    } | null = null
+  ) =>
+    program.methods
+      .refundEntryFee()
+      .accountsPartial({
+        contest: t.contest,
+        submission: submissionPda(t.contest, entrant.publicKey),
+        participant: entrant.publicKey,
+        escrow: t.escrow,
+        feeMint: null,
+        escrowFeeAccount: null,
+        participantFeeAccount: null,
+        tokenProgram: tokens ? TOKEN_PROGRAM_ID : null,
+        ...tokens,
+      })
+      .rpc();
+
+  /** Contest whose reclaim time-lock opens one second after the deadline */
+  const shortLivedContest = () =>
+    withConfig({ reclaimDelay: new BN(1) }, () =>
+      createContest({ deadlineSecs: 8 })
+    );
+
+  it("splits lamport fees between the winner and the organizer", async () => {
+    const fee = LAMPORTS_PER_SOL / 100;
+    const t = await createContest({ deadlineSecs: 10 });
+    await configureEntryFee(t, fee, null, 7_000);
+    await fundContest(t);
+
+    const escrowBefore = await balance(t.escrow);
+    const entrants = [
+      await fundedKeypair(),
+      await fundedKeypair(),
+      await fundedKeypair(),
+    ];
+    for (const [i, entrant] of entrants.entries()) {
+      await submitEntry(t.contest, entrant, `https://example.com/${i}`, {
+        fees: { escrow: t.escrow },
+      }).rpc();
+    }
+    let contest = await program.account.contest.fetch(t.contest);
+    expect(contest.entryFeePool.toNumber()).to.equal(3 * fee);
+    expect((await balance(t.escrow)) - escrowBefore).to.equal(3 * fee);
+
+    const winner = entrants[0].publicKey;
+    await waitUntil(t.deadline);
+    await judgeVote(t, winner);
+    await distributePrizes(t, winner);
+
+    const winnerBefore = await balance(winner);
+    const creatorBefore = await balance(t.creator.publicKey);
+    const settle = () =>
+      program.methods
+        .settleEntryFees()
+        .accountsPartial({
+          contest: t.contest,
+          escrow: t.escrow,
+          winner,
+          authority: t.creator.publicKey,
+          feeMint: null,
+          escrowFeeAccount: null,
+          winnerFeeAccount: null,
+          authorityFeeAccount: null,
+          tokenProgram: null,
+        })
+        .rpc();
+    await settle();
+
+    const prizeShare = (3 * fee * 7_000) / 10_000;
+    expect((await balance(winner)) - winnerBefore).to.equal(prizeShare);
+    expect((await balance(t.creator.publicKey)) - creatorBefore).to.equal(
+      3 * fee - prizeShare
+    );
+    contest = await program.account.contest.fetch(t.contest);
+    expect(contest.entryFeePool.toNumber()).to.equal(0);
+    expect(await errorCode(settle())).to.equal("NoEntryFees");
+  });
+
+  it("collects and settles token fees through the escrow's token account", async () => {
+    const fee = 1_000_000;
+    const mint = await createMint();
+    const t = await createContest({ deadlineSecs: 10 });
+    await configureEntryFee(t, fee, mint, 5_000);
+    await fundContest(t);
+    const escrowFeeAccount = await createTokenAccount(mint, t.escrow);
+
+    const entrants = [await fundedKeypair(), await fundedKeypair()];
+    for (const [i, entrant] of entrants.entries()) {
+      const participantFeeAccount = await createTokenAccount(
+        mint,
+        entrant.publicKey
+      );
+      await mintTo(mint, participantFeeAccount, 5 * fee);
+      await submitEntry(t.contest, entrant, `https://example.com/${i}`, {
+        fees: {
+          escrow: t.escrow,
+          feeMint: mint,
+          participantFeeAccount,
+          escrowFeeAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        },
+      }).rpc();
+      expect(await tokenBalance(participantFeeAccount)).to.equal(4 * fee);
+    }
+    expect(await tokenBalance(escrowFeeAccount)).to.equal(2 * fee);
+
+    const winner = entrants[1].publicKey;
+    await waitUntil(t.deadline);
+    await judgeVote(t, winner);
+    await distributePrizes(t, winner);
+
+    const winnerFeeAccount = anchor.utils.token.associatedAddress({
+      mint,
+      owner: winner,
+    });
+    const authorityFeeAccount = await createTokenAccount(
+      mint,
+      t.creator.publicKey
+    );
+    await program.methods
+      .settleEntryFees()
+      .accountsPartial({
+        contest: t.contest,
+        escrow: t.escrow,
+        winner,
+        authority: t.creator.publicKey,
+        feeMint: mint,
+        escrowFeeAccount,
+        winnerFeeAccount,
+        authorityFeeAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    expect(await tokenBalance(escrowFeeAccount)).to.equal(0);
+    expect(await tokenBalance(winnerFeeAccount)).to.equal(4 * fee + fee);
+    expect(await tokenBalance(authorityFeeAccount)).to.equal(fee);
+  });
+
+  it("only refunds fees once the contest is cancelled", async () => {
+    const fee = LAMPORTS_PER_SOL / 100;
+    const t = await createContest();
+    await configureEntryFee(t, fee, null, 0);
+    await fundContest(t);
+    const entrant = await fundedKeypair();
+    await submitEntry(t.contest, entrant, "https://example.com/entry", {
+      fees: { escrow: t.escrow },
+    }).rpc();
+
+    expect(await errorCode(refund(t, entrant))).to.equal(
+      "InvalidContestState"
+    );
+  });
+
+  it("refunds each lamport fee once after cancellation", async () => {
+    const fee = LAMPORTS_PER_SOL / 100;
+    const t = await shortLivedContest();
+    await configureEntryFee(t, fee, null, 0);
+    await fundContest(t);
+    const entrants = [await fundedKeypair(), await fundedKeypair()];
+    for (const [i, entrant] of entrants.entries()) {
+      await submitEntry(t.contest, entrant, `https://example.com/${i}`, {
+        fees: { escrow: t.escrow },
+      }).rpc();
+    }
+
+    await waitUntil(t.deadline.addn(1));
+    await reclaimFunds(t);
+    // The reclaim leaves the collected fees in escrow
+    expect(await balance(t.escrow)).to.equal(2 * fee);
+
+    const before = await balance(entrants[0].publicKey);
+    await refund(t, entrants[0]);
+    expect((await balance(entrants[0].publicKey)) - before).to.equal(fee);
+    expect(await errorCode(refund(t, entrants[0]))).to.equal(
+      "EntryFeeAlreadyRefunded"
+    );
+
+    await refund(t, entrants[1]);
+    const contest = await program.account.contest.fetch(t.contest);
+    expect(contest.entryFeePool.toNumber()).to.equal(0);
+  });
+
+  it("refunds token fees to the participant's token account", async () => {
+    const fee = 1_000_000;
+    const mint = await createMint();
+    const t = await shortLivedContest();
+    await configureEntryFee(t, fee, mint, 0);
+    await fundContest(t);
+    const escrowFeeAccount = await createTokenAccount(mint, t.escrow);
+
+    const entrant = await fundedKeypair();
+    const participantFeeAccount = await createTokenAccount(
+      mint,
+      entrant.publicKey
+    );
+    await mintTo(mint, participantFeeAccount, 2 * fee);
+    await submitEntry(t.contest, entrant, "https://example.com/entry", {
+      fees: {
+        escrow: t.escrow,
+        feeMint: mint,
+        participantFeeAccount,
+        escrowFeeAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+    }).rpc();
+    expect(await tokenBalance(participantFeeAccount)).to.equal(fee);
+
+    await waitUntil(t.deadline.addn(1));
+    await reclaimFunds(t);
+    const tokens = { feeMint: mint, escrowFeeAccount, participantFeeAccount };
+    await refund(t, entrant, tokens);
+
+    expect(await tokenBalance(participantFeeAccount)).to.equal(2 * fee);
+    expect(await tokenBalance(escrowFeeAccount)).to.equal(0);
+    expect(await errorCode(refund(t, entrant, tokens))).to.equal(
+      "EntryFeeAlreadyRefunded"
+    );
+  });
+});
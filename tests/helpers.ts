@@ -0,0 +1,369 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { createHash } from "crypto";
+import { Solarena } from "../target/types/solarena";
+
+const { Keypair, LAMPORTS_PER_SOL, PublicKey } = anchor.web3;
+type PublicKey = anchor.web3.PublicKey;
+type Keypair = anchor.web3.Keypair;
+
+anchor.setProvider(anchor.AnchorProvider.env());
+
+export const provider = anchor.getProvider() as anchor.AnchorProvider;
+export const program = anchor.workspace.solarena as Program<Solarena>;
+
+const BPF_LOADER_UPGRADEABLE = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+export const CREATOR_INDEX_PAGE_SIZE = 32;
+
+export const u32 = (n: number) => new BN(n).toArrayLike(Buffer, "le", 4);
+export const u64 = (n: number | BN) => new BN(n).toArrayLike(Buffer, "le", 8);
+
+/** Derives a SolArena PDA */
+export function pda(...seeds: (Buffer | Uint8Array)[]): PublicKey {
+  return PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+}
+
+export const configPda = () => pda(Buffer.from("config"));
+export const registryPda = () => pda(Buffer.from("registry"));
+export const gasPoolPda = (contest: PublicKey) =>
+  pda(Buffer.from("gas_pool"), contest.toBuffer());
+export const submissionPda = (contest: PublicKey, participant: PublicKey) =>
+  pda(Buffer.from("submission"), contest.toBuffer(), participant.toBuffer());
+export const votePda = (contest: PublicKey, judge: PublicKey, track = 0) =>
+  pda(
+    Buffer.from("vote"),
+    contest.toBuffer(),
+    judge.toBuffer(),
+    Buffer.from([track])
+  );
+
+/** Returns the Anchor error code name of a failed transaction */
+export async function errorCode(promise: Promise<unknown>): Promise<string> {
+  try {
+    await promise;
+  } catch (e) {
+    return (e as anchor.AnchorError).error?.errorCode?.code ?? String(e);
+  }
+  throw new Error("expected the transaction to fail");
+}
+
+export async function fundedKeypair(sol = 5): Promise<Keypair> {
+  const keypair = Keypair.generate();
+  const signature = await provider.connection.requestAirdrop(
+    keypair.publicKey,
+    sol * LAMPORTS_PER_SOL
+  );
+  await provider.connection.confirmTransaction(signature, "confirmed");
+  return keypair;
+}
+
+export const balance = (address: PublicKey) =>
+  provider.connection.getBalance(address, "confirmed");
+
+/** Waits until the validator clock reaches `unixTime` */
+export async function waitUntil(unixTime: BN | number): Promise<void> {
+  const target = new BN(unixTime).toNumber();
+  for (;;) {
+    const slot = await provider.connection.getSlot("confirmed");
+    const now = await provider.connection.getBlockTime(slot);
+    if (now !== null && now >= target) return;
+    await new Promise((resolve) => setTimeout(resolve, 500));
+  }
+}
+
+/** Creates the config and registry singletons once per validator */
+export async function ensureProtocol(): Promise<void> {
+  const connection = provider.connection;
+  if (!(await connection.getAccountInfo(configPda()))) {
+    const [programData] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE
+    );
+    await program.methods
+      .initializeConfig(provider.wallet.publicKey, {
+        minPrizeAmount: new BN(LAMPORTS_PER_SOL / 100),
+        maxJudges: 5,
+        reclaimDelay: new BN(3600),
+        maxUrlLen: 200,
+        platformFeeBps: 0,
+        relayer: null,
+      })
+      .accountsPartial({ programData, authority: provider.wallet.publicKey })
+      .rpc();
+  }
+  if (!(await connection.getAccountInfo(registryPda()))) {
+    await program.methods
+      .initializeRegistry()
+      .accountsPartial({ payer: provider.wallet.publicKey })
+      .rpc();
+  }
+}
+
+type ConfigParams = Parameters<typeof program.methods.updateConfig>[0];
+
+const updateConfig = (params: ConfigParams) =>
+  program.methods
+    .updateConfig(params)
+    .accountsPartial({ admin: provider.wallet.publicKey })
+    .rpc();
+
+/**
+ * Runs `body` with some protocol settings overridden, then restores them.
+ * Contests snapshot the reclaim delay and platform fee when created.
+ */
+export async function withConfig<T>(
+  changes: Partial<ConfigParams>,
+  body: () => Promise<T>
+): Promise<T> {
+  await ensureProtocol();
+  const config = await program.account.config.fetch(configPda());
+  const current: ConfigParams = {
+    minPrizeAmount: config.minPrizeAmount,
+    maxJudges: config.maxJudges,
+    reclaimDelay: config.reclaimDelay,
+    maxUrlLen: config.maxUrlLen,
+    platformFeeBps: config.platformFeeBps,
+    relayer: config.relayer,
+  };
+  await updateConfig({ ...current, ...changes });
+  try {
+    return await body();
+  } finally {
+    await updateConfig(current);
+  }
+}
+
+export interface ContestOptions {
+  judges?: PublicKey[];
+  threshold?: number;
+  deadlineSecs?: number;
+  allowlistRoot?: number[] | null;
+  prize?: BN;
+  creator?: Keypair;
+}
+
+export interface TestContest {
+  contest: PublicKey;
+  contestId: BN;
+  creator: Keypair;
+  escrow: PublicKey;
+  indexPage: PublicKey;
+  deadline: BN;
+}
+
+/** Creates a contest from a fresh creator wallet, judged by the provider wallet */
+export async function createContest(
+  options: ContestOptions = {}
+): Promise<TestContest> {
+  await ensureProtocol();
+  const creator = options.creator ?? (await fundedKeypair(10));
+  const contestId = (await program.account.registry.fetch(registryPda()))
+    .contestCount;
+  const contest = pda(
+    Buffer.from("contest"),
+    creator.publicKey.toBuffer(),
+    u64(contestId)
+  );
+  const creatorIndex = pda(
+    Buffer.from("creator_index"),
+    creator.publicKey.toBuffer()
+  );
+  const indexed =
+    (await program.account.creatorIndex.fetchNullable(creatorIndex))
+      ?.contestCount ?? 0;
+  const indexPage = pda(
+    Buffer.from("creator_index"),
+    creator.publicKey.toBuffer(),
+    u32(Math.floor(indexed / CREATOR_INDEX_PAGE_SIZE))
+  );
+  const deadline = new BN(
+    Math.floor(Date.now() / 1000) + (options.deadlineSecs ?? 3600)
+  );
+
+  await program.methods
+    .createContest(
+      "Test contest",
+      "Created by the test suite",
+      options.prize ?? new BN(LAMPORTS_PER_SOL / 10),
+      deadline,
+      options.judges ?? [provider.wallet.publicKey],
+      options.threshold ?? 1,
+      options.allowlistRoot ?? null
+    )
+    .accountsPartial({
+      contest,
+      creatorIndex,
+      indexPage,
+      creator: creator.publicKey,
+    })
+    .signers([creator])
+    .rpc();
+
+  const escrow = pda(
+    Buffer.from("escrow"),
+    creator.publicKey.toBuffer(),
+    u64(contestId)
+  );
+  return { contest, contestId, creator, escrow, indexPage, deadline };
+}
+
+/** Cancels an unjudged contest once its reclaim time-lock has passed */
+export async function reclaimFunds(t: TestContest): Promise<void> {
+  await program.methods
+    .reclaimFunds()
+    .accountsPartial({
+      contest: t.contest,
+      escrow: t.escrow,
+      authority: t.creator.publicKey,
+    })
+    .signers([t.creator])
+    .rpc();
+}
+
+export async function fundContest(t: TestContest): Promise<void> {
+  await program.methods
+    .fundContest()
+    .accountsPartial({
+      contest: t.contest,
+      escrow: t.escrow,
+      authority: t.creator.publicKey,
+    })
+    .signers([t.creator])
+    .rpc();
+}
+
+/** Escrow and token accounts a fee-charging contest needs on entry */
+export interface EntryFeeAccounts {
+  escrow: PublicKey;
+  feeMint?: PublicKey;
+  participantFeeAccount?: PublicKey;
+  escrowFeeAccount?: PublicKey;
+  tokenProgram?: PublicKey;
+}
+
+export interface EntryOptions {
+  allowlistProof?: number[][];
+  commitment?: number[] | null;
+  track?: number;
+  fees?: EntryFeeAccounts;
+}
+
+/** Builds a `submit_entry` call paid for by the participant */
+export function submitEntry(
+  contest: PublicKey,
+  participant: Keypair,
+  url: string,
+  options: EntryOptions = {}
+) {
+  return program.methods
+    .submitEntry(
+      url,
+      options.allowlistProof ?? [],
+      options.commitment ?? null,
+      options.track ?? 0
+    )
+    .accountsPartial({
+      contest,
+      submission: submissionPda(contest, participant.publicKey),
+      registration: null,
+      participant: participant.publicKey,
+      payer: participant.publicKey,
+      gasPool: gasPoolPda(contest),
+      escrow: null,
+      feeMint: null,
+      participantFeeAccount: null,
+      escrowFeeAccount: null,
+      tokenProgram: null,
+      gateTokenAccount: null,
+      gateNftMetadata: null,
+      ...options.fees,
+    })
+    .signers([participant]);
+}
+
+/** Casts the provider wallet's judge vote */
+export async function judgeVote(
+  t: TestContest,
+  winner: PublicKey,
+  track = 0
+): Promise<void> {
+  await program.methods
+    .judgeVote(winner, track)
+    .accountsPartial({
+      contest: t.contest,
+      vote: votePda(t.contest, provider.wallet.publicKey, track),
+      judge: provider.wallet.publicKey,
+    })
+    .rpc();
+}
+
+/** Pays the (track) prize to `winner`, counting the provider wallet's vote */
+export async function distributePrizes(
+  t: TestContest,
+  winner: PublicKey,
+  track = 0
+): Promise<void> {
+  await program.methods
+    .distributePrizes(track)
+    .accountsPartial({
+      contest: t.contest,
+      escrow: t.escrow,
+      winner,
+      winnerSubmission: submissionPda(t.contest, winner),
+    })
+    .remainingAccounts([
+      {
+        pubkey: votePda(t.contest, provider.wallet.publicKey, track),
+        isSigner: false,
+        isWritable: false,
+      },
+    ])
+    .rpc();
+}
+
+const sha256 = (...parts: Buffer[]) =>
+  createHash("sha256").update(Buffer.concat(parts)).digest();
+
+/** Sealed-entry commitment: sha256(url || salt) */
+export const entryCommitment = (url: string, salt: Buffer) =>
+  sha256(Buffer.from(url), salt);
+
+/** Allowlist leaf: sha256(0x00 || participant) */
+export const allowlistLeaf = (participant: PublicKey) =>
+  sha256(Buffer.from([0]), participant.toBuffer());
+
+/** Allowlist inner node: sha256(0x01 || min(a, b) || max(a, b)) */
+export const allowlistNode = (a: Buffer, b: Buffer) =>
+  Buffer.compare(a, b) <= 0
+    ? sha256(Buffer.from([1]), a, b)
+    : sha256(Buffer.from([1]), b, a);
+
+export interface SubmissionMetadataArgs {
+  title: string;
+  repoUrl: string;
+  demoUrl: string;
+  videoUrl: string;
+  description: string;
+}
+
+/** Builds an `update_submission` call paid for by the participant */
+export function updateSubmission(
+  contest: PublicKey,
+  participant: Keypair,
+  url: string,
+  metadata: SubmissionMetadataArgs | null
+) {
+  return program.methods
+    .updateSubmission(url, null, metadata)
+    .accountsPartial({
+      contest,
+      submission: submissionPda(contest, participant.publicKey),
+      participant: participant.publicKey,
+      payer: participant.publicKey,
+      gasPool: gasPoolPda(contest),
+    })
+    .signers([participant]);
+}
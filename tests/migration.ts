@@ -0,0 +1,43 @@
+import { expect } from "chai";
+import {
+  createContest,
+  errorCode,
+  fundedKeypair,
+  program,
+  provider,
+} from "./helpers";
+
+describe("migration", () => {
+  it("leaves a current contest untouched", async () => {
+    const t = await createContest();
+    const before = await program.account.contest.fetch(t.contest);
+
+    await program.methods
+      .migrateContest()
+      .accountsPartial({ contest: t.contest, payer: provider.wallet.publicKey })
+      .rpc();
+
+    const after = await program.account.contest.fetch(t.contest);
+    expect(after.version).to.equal(1);
+    expect(after.openAccounts).to.equal(before.openAccounts);
+  });
+
+  it("rejects accounts the program does not own", async () => {
+    const t = await createContest();
+    const stranger = await fundedKeypair(1);
+
+    const code = await errorCode(
+      program.methods
+        .migrateContest()
+        .accountsPartial({
+          contest: t.contest,
+          payer: provider.wallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: stranger.publicKey, isSigner: false, isWritable: true },
+        ])
+        .rpc()
+    );
+    expect(code).to.equal("InvalidMigrationAccount");
+  });
+});
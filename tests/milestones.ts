@@ -0,0 +1,159 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { randomBytes } from "crypto";
+import {
+  balance,
+  createContest,
+  distributePrizes,
+  errorCode,
+  fundContest,
+  fundedKeypair,
+  judgeVote,
+  pda,
+  program,
+  provider,
+  reclaimFunds,
+  submitEntry,
+  TestContest,
+  waitUntil,
+  withConfig,
+} from "./helpers";
+
+const { LAMPORTS_PER_SOL } = anchor.web3;
+type Keypair = anchor.web3.Keypair;
+
+describe("milestones", () => {
+  const amounts = [LAMPORTS_PER_SOL / 25, (LAMPORTS_PER_SOL * 6) / 100];
+
+  const milestonePda = (t: TestContest, index: number) =>
+    pda(Buffer.from("milestone"), t.contest.toBuffer(), Buffer.from([index]));
+
+  const addMilestone = (t: TestContest, index: number, amount: number) =>
+    program.methods
+      .addMilestone(index, [...randomBytes(32)], new BN(amount))
+      .accountsPartial({
+        contest: t.contest,
+        milestone: milestonePda(t, index),
+        authority: t.creator.publicKey,
+      })
+      .signers([t.creator])
+      .rpc();
+
+  const submitProof = (t: TestContest, index: number, winner: Keypair) =>
+    program.methods
+      .submitMilestoneProof([...randomBytes(32)])
+      .accountsPartial({
+        contest: t.contest,
+        milestone: milestonePda(t, index),
+        winner: winner.publicKey,
+      })
+      .signers([winner])
+      .rpc();
+
+  const approve = (t: TestContest, index: number, winner: Keypair) =>
+    program.methods
+      .approveMilestone()
+      .accountsPartial({
+        contest: t.contest,
+        milestone: milestonePda(t, index),
+        escrow: t.escrow,
+        winner: winner.publicKey,
+        judge: provider.wallet.publicKey,
+      })
+      .rpc();
+
+  it("releases each milestone separately once approved", async () => {
+    const t = await createContest({ deadlineSecs: 8 });
+    await addMilestone(t, 0, amounts[0]);
+    await addMilestone(t, 1, amounts[1]);
+    await fundContest(t);
+
+    const alice = await fundedKeypair();
+    await submitEntry(t.contest, alice, "https://example.com/alice").rpc();
+    await waitUntil(t.deadline);
+    await judgeVote(t, alice.publicKey);
+
+    // Picking the winner releases nothing yet
+    let before = await balance(alice.publicKey);
+    await distributePrizes(t, alice.publicKey);
+    expect(await balance(alice.publicKey)).to.equal(before);
+    let contest = await program.account.contest.fetch(t.contest);
+    expect(contest.winner.toBase58()).to.equal(alice.publicKey.toBase58());
+
+    expect(await errorCode(approve(t, 0, alice))).to.equal(
+      "MilestoneProofMissing"
+    );
+    await submitProof(t, 0, alice);
+    before = await balance(alice.publicKey);
+    await approve(t, 0, alice);
+    expect((await balance(alice.publicKey)) - before).to.equal(amounts[0]);
+    expect(await errorCode(approve(t, 0, alice))).to.equal(
+      "MilestoneAlreadyReleased"
+    );
+    contest = await program.account.contest.fetch(t.contest);
+    expect(contest.milestonesReleased).to.equal(1);
+    expect(contest.status).to.deep.equal({ active: {} });
+
+    await submitProof(t, 1, alice);
+    before = await balance(alice.publicKey);
+    await approve(t, 1, alice);
+    expect((await balance(alice.publicKey)) - before).to.equal(amounts[1]);
+    contest = await program.account.contest.fetch(t.contest);
+    expect(contest.status).to.deep.equal({ completed: {} });
+  });
+
+  it("only lets the winner submit proof", async () => {
+    const t = await createContest({ deadlineSecs: 8 });
+    await addMilestone(t, 0, amounts[0] + amounts[1]);
+    await fundContest(t);
+
+    const alice = await fundedKeypair();
+    const mallory = await fundedKeypair();
+    await submitEntry(t.contest, alice, "https://example.com/alice").rpc();
+    await waitUntil(t.deadline);
+    await judgeVote(t, alice.publicKey);
+    await distributePrizes(t, alice.publicKey);
+
+    expect(await errorCode(submitProof(t, 0, mallory))).to.equal(
+      "InvalidWinner"
+    );
+  });
+
+  it("requires milestone amounts to add up to the prize", async () => {
+    const t = await createContest();
+    await addMilestone(t, 0, amounts[0]);
+
+    expect(await errorCode(fundContest(t))).to.equal("MilestoneTotalMismatch");
+  });
+
+  it("returns unapproved milestones to the authority after the time-lock", async () => {
+    // Reclaim opens one second after the deadline
+    const t = await withConfig({ reclaimDelay: new BN(1) }, () =>
+      createContest({ deadlineSecs: 8 })
+    );
+    await addMilestone(t, 0, amounts[0]);
+    await addMilestone(t, 1, amounts[1]);
+    await fundContest(t);
+
+    const alice = await fundedKeypair();
+    await submitEntry(t.contest, alice, "https://example.com/alice").rpc();
+    await waitUntil(t.deadline);
+    await judgeVote(t, alice.publicKey);
+    await distributePrizes(t, alice.publicKey);
+    await submitProof(t, 0, alice);
+    await approve(t, 0, alice);
+
+    await waitUntil(t.deadline.addn(1));
+    const before = await balance(t.creator.publicKey);
+    await reclaimFunds(t);
+    expect((await balance(t.creator.publicKey)) - before).to.equal(amounts[1]);
+
+    const contest = await program.account.contest.fetch(t.contest);
+    expect(contest.status).to.deep.equal({ cancelled: {} });
+    await submitProof(t, 1, alice);
+    expect(await errorCode(approve(t, 1, alice))).to.equal(
+      "InvalidContestState"
+    );
+  });
+});
@@ -0,0 +1,70 @@
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  createContest,
+  ensureProtocol,
+  errorCode,
+  fundedKeypair,
+  pda,
+  program,
+  registryPda,
+  u32,
+  u64,
+} from "./helpers";
+
+describe("registry", () => {
+  before(ensureProtocol);
+
+  it("assigns sequential IDs and indexes them per creator", async () => {
+    const creator = await fundedKeypair(10);
+    const first = await createContest({ creator });
+    const second = await createContest({ creator });
+
+    expect(second.contestId.toNumber()).to.be.greaterThan(
+      first.contestId.toNumber()
+    );
+    const page = await program.account.creatorIndexPage.fetch(first.indexPage);
+    expect(page.contestIds.map((id) => id.toNumber())).to.deep.equal([
+      first.contestId.toNumber(),
+      second.contestId.toNumber(),
+    ]);
+    const index = await program.account.creatorIndex.fetch(
+      pda(Buffer.from("creator_index"), creator.publicKey.toBuffer())
+    );
+    expect(index.contestCount).to.equal(2);
+  });
+
+  it("rejects an index page other than the creator's next page", async () => {
+    const creator = await fundedKeypair(10);
+    const contestId = (await program.account.registry.fetch(registryPda()))
+      .contestCount;
+    const seed = (...parts: Buffer[]) =>
+      pda(Buffer.from("creator_index"), creator.publicKey.toBuffer(), ...parts);
+
+    const code = await errorCode(
+      program.methods
+        .createContest(
+          "Wrong page",
+          "",
+          new BN(100_000_000),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          [creator.publicKey],
+          1,
+          null
+        )
+        .accountsPartial({
+          contest: pda(
+            Buffer.from("contest"),
+            creator.publicKey.toBuffer(),
+            u64(contestId)
+          ),
+          creatorIndex: seed(),
+          indexPage: seed(u32(1)),
+          creator: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc()
+    );
+    expect(code).to.equal("ConstraintSeeds");
+  });
+});
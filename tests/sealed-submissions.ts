@@ -0,0 +1,110 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { randomBytes } from "crypto";
+import {
+  createContest,
+  distributePrizes,
+  entryCommitment,
+  errorCode,
+  fundContest,
+  fundedKeypair,
+  judgeVote,
+  program,
+  submissionPda,
+  submitEntry,
+  TestContest,
+  waitUntil,
+} from "./helpers";
+
+describe("sealed submissions", () => {
+  const seal = (t: TestContest, revealPeriod: number) =>
+    program.methods
+      .configureSealedSubmissions(new BN(revealPeriod))
+      .accountsPartial({ contest: t.contest, authority: t.creator.publicKey })
+      .signers([t.creator])
+      .rpc();
+
+  const reveal = (
+    t: TestContest,
+    participant: anchor.web3.Keypair,
+    url: string,
+    salt: Buffer
+  ) =>
+    program.methods
+      .revealEntry(url, [...salt])
+      .accountsPartial({
+        contest: t.contest,
+        submission: submissionPda(t.contest, participant.publicKey),
+        participant: participant.publicKey,
+      })
+      .signers([participant])
+      .rpc();
+
+  it("reveals a matching URL only after the deadline", async () => {
+    const t = await createContest({ deadlineSecs: 8 });
+    await seal(t, 600);
+    await fundContest(t);
+
+    const alice = await fundedKeypair();
+    const url = "https://example.com/alice";
+    const salt = randomBytes(32);
+    await submitEntry(t.contest, alice, "", {
+      commitment: [...entryCommitment(url, salt)],
+    }).rpc();
+
+    const address = submissionPda(t.contest, alice.publicKey);
+    let submission = await program.account.submission.fetch(address);
+    expect(submission.submissionUrl).to.equal("");
+    expect(submission.revealed).to.equal(false);
+    expect(await errorCode(reveal(t, alice, url, salt))).to.equal(
+      "RevealWindowNotOpen"
+    );
+
+    await waitUntil(t.deadline);
+    expect(await errorCode(reveal(t, alice, url, randomBytes(32)))).to.equal(
+      "CommitmentMismatch"
+    );
+    await reveal(t, alice, url, salt);
+
+    submission = await program.account.submission.fetch(address);
+    expect(submission.submissionUrl).to.equal(url);
+    expect(submission.revealed).to.equal(true);
+    // Judging waits for the reveal window to close
+    expect(await errorCode(judgeVote(t, alice.publicKey))).to.equal(
+      "RevealPeriodNotEnded"
+    );
+  });
+
+  it("rejects plain URLs in sealed contests", async () => {
+    const t = await createContest();
+    await seal(t, 600);
+    await fundContest(t);
+
+    const alice = await fundedKeypair();
+    const code = await errorCode(
+      submitEntry(t.contest, alice, "https://example.com/alice").rpc()
+    );
+    expect(code).to.equal("InvalidSubmissionMode");
+  });
+
+  it("excludes entries that were never revealed from winning", async () => {
+    const revealPeriod = 4;
+    const t = await createContest({ deadlineSecs: 8 });
+    await seal(t, revealPeriod);
+    await fundContest(t);
+
+    const bob = await fundedKeypair();
+    await submitEntry(t.contest, bob, "", {
+      commitment: [
+        ...entryCommitment("https://example.com/bob", randomBytes(32)),
+      ],
+    }).rpc();
+
+    await waitUntil(t.deadline.addn(revealPeriod));
+    await judgeVote(t, bob.publicKey);
+    expect(await errorCode(distributePrizes(t, bob.publicKey))).to.equal(
+      "SubmissionNotRevealed"
+    );
+  });
+});
@@ -0,0 +1,138 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  balance,
+  ensureProtocol,
+  errorCode,
+  fundedKeypair,
+  pda,
+  program,
+  provider,
+  registryPda,
+  u32,
+  u64,
+} from "./helpers";
+
+const { LAMPORTS_PER_SOL } = anchor.web3;
+type Keypair = anchor.web3.Keypair;
+type PublicKey = anchor.web3.PublicKey;
+
+describe("series", () => {
+  const prize = new BN(LAMPORTS_PER_SOL / 10);
+
+  before(ensureProtocol);
+
+  async function createSeries(creator: Keypair) {
+    const seriesId = new BN(Date.now());
+    const series = pda(
+      Buffer.from("series"),
+      creator.publicKey.toBuffer(),
+      u64(seriesId)
+    );
+    const vault = pda(Buffer.from("series_vault"), series.toBuffer());
+    await program.methods
+      .createSeries(
+        seriesId,
+        {
+          title: "Weekly round",
+          description: "Opened by the test suite",
+          prizeAmount: prize,
+          judges: [provider.wallet.publicKey],
+          approvalThreshold: 1,
+          roundDuration: new BN(600),
+          roundInterval: new BN(3600),
+          firstRoundAt: new BN(Math.floor(Date.now() / 1000) - 10),
+          maxRounds: 0,
+        },
+        new BN(LAMPORTS_PER_SOL)
+      )
+      .accountsPartial({ series, vault, creator: creator.publicKey })
+      .signers([creator])
+      .rpc();
+    return { series, vault };
+  }
+
+  /** Accounts for the next round; the creator's first round lands on index page 0 */
+  async function roundAccounts(creator: PublicKey) {
+    const contestId = (await program.account.registry.fetch(registryPda()))
+      .contestCount;
+    const seed = (name: string, ...parts: Buffer[]) =>
+      pda(Buffer.from(name), creator.toBuffer(), ...parts);
+    return {
+      contestId,
+      contest: seed("contest", u64(contestId)),
+      creatorIndex: seed("creator_index"),
+      indexPage: seed("creator_index", u32(0)),
+      escrow: seed("escrow", u64(contestId)),
+    };
+  }
+
+  const openNextRound = (
+    series: PublicKey,
+    round: Awaited<ReturnType<typeof roundAccounts>>,
+    cranker: Keypair
+  ) =>
+    program.methods
+      .openNextRound()
+      .accountsPartial({
+        series,
+        contest: round.contest,
+        creatorIndex: round.creatorIndex,
+        indexPage: round.indexPage,
+        escrow: round.escrow,
+        cranker: cranker.publicKey,
+      })
+      .signers([cranker])
+      .rpc();
+
+  it("opens a funded round from the vault and reimburses the cranker", async () => {
+    const creator = await fundedKeypair(10);
+    const cranker = await fundedKeypair();
+    const { series, vault } = await createSeries(creator);
+
+    const round = await roundAccounts(creator.publicKey);
+    const vaultBefore = await balance(vault);
+    const crankerBefore = await balance(cranker.publicKey);
+    await openNextRound(series, round, cranker);
+
+    // The vault pays the prize plus the rent of every account the round created
+    const rent =
+      (await balance(round.contest)) +
+      (await balance(round.creatorIndex)) +
+      (await balance(round.indexPage));
+    expect(vaultBefore - (await balance(vault))).to.equal(
+      prize.toNumber() + rent
+    );
+    // The provider wallet pays the fee, so the cranker ends up even
+    expect(await balance(cranker.publicKey)).to.equal(crankerBefore);
+    expect(await balance(round.escrow)).to.equal(prize.toNumber());
+
+    const contest = await program.account.contest.fetch(round.contest);
+    expect(contest.contestId.toString()).to.equal(round.contestId.toString());
+    expect(contest.creator.toBase58()).to.equal(creator.publicKey.toBase58());
+    expect(contest.series.toBase58()).to.equal(series.toBase58());
+    expect(contest.funded).to.equal(true);
+    expect(contest.status).to.deep.equal({ active: {} });
+
+    const state = await program.account.series.fetch(series);
+    expect(state.roundsOpened).to.equal(1);
+    expect(state.currentContest.toBase58()).to.equal(round.contest.toBase58());
+    const registry = await program.account.registry.fetch(registryPda());
+    expect(registry.contestCount.gt(round.contestId)).to.equal(true);
+  });
+
+  it("does not open the same round twice", async () => {
+    const creator = await fundedKeypair(10);
+    const { series, vault } = await createSeries(creator);
+    await openNextRound(series, await roundAccounts(creator.publicKey), creator);
+
+    const vaultBefore = await balance(vault);
+    const code = await errorCode(
+      openNextRound(series, await roundAccounts(creator.publicKey), creator)
+    );
+    expect(code).to.equal("SeriesRoundNotDue");
+    expect(await balance(vault)).to.equal(vaultBefore);
+    expect((await program.account.series.fetch(series)).roundsOpened).to.equal(1);
+  });
+});
@@ -0,0 +1,61 @@
+import { expect } from "chai";
+import {
+  createContest,
+  errorCode,
+  fundContest,
+  fundedKeypair,
+  program,
+  provider,
+  submissionPda,
+  submitEntry,
+  updateSubmission,
+} from "./helpers";
+
+describe("submission metadata", () => {
+  const details = {
+    title: "SolArena",
+    repoUrl: "https://github.com/example/solarena",
+    demoUrl: "",
+    videoUrl: "https://example.com/video",
+    description: "A contest platform",
+  };
+
+  it("grows the submission account to fit new project details", async () => {
+    const t = await createContest();
+    await fundContest(t);
+    const alice = await fundedKeypair();
+    await submitEntry(t.contest, alice, "https://example.com/alice").rpc();
+
+    const address = submissionPda(t.contest, alice.publicKey);
+    const before = (await provider.connection.getAccountInfo(address)).data
+      .length;
+    await updateSubmission(t.contest, alice, "", details).rpc();
+    const after = (await provider.connection.getAccountInfo(address)).data
+      .length;
+
+    const usedBytes =
+      details.title.length +
+      details.repoUrl.length +
+      details.videoUrl.length +
+      details.description.length;
+    expect(after - before).to.equal(usedBytes);
+    const submission = await program.account.submission.fetch(address);
+    expect(submission.metadata.title).to.equal(details.title);
+    expect(submission.submissionUrl).to.equal("https://example.com/alice");
+  });
+
+  it("rejects a project title over 64 characters", async () => {
+    const t = await createContest();
+    await fundContest(t);
+    const alice = await fundedKeypair();
+    await submitEntry(t.contest, alice, "https://example.com/alice").rpc();
+
+    const code = await errorCode(
+      updateSubmission(t.contest, alice, "", {
+        ...details,
+        title: "x".repeat(65),
+      }).rpc()
+    );
+    expect(code).to.equal("ProjectTitleTooLong");
+  });
+});
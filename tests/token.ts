@@ -0,0 +1,104 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { provider } from "./helpers";
+
+// Just enough of the SPL Token program for the tests, without pulling in
+// @solana/spl-token
+
+const { Keypair, SystemProgram, Transaction, TransactionInstruction } =
+  anchor.web3;
+type PublicKey = anchor.web3.PublicKey;
+
+export const TOKEN_PROGRAM_ID = anchor.utils.token.TOKEN_PROGRAM_ID;
+const ASSOCIATED_TOKEN_PROGRAM_ID = anchor.utils.token.ASSOCIATED_PROGRAM_ID;
+
+const MINT_SIZE = 82;
+
+const send = (...instructions: anchor.web3.TransactionInstruction[]) =>
+  (signers: anchor.web3.Signer[] = []) =>
+    provider.sendAndConfirm(new Transaction().add(...instructions), signers);
+
+/** Creates a mint whose authority is the provider wallet */
+export async function createMint(decimals = 6): Promise<PublicKey> {
+  const mint = Keypair.generate();
+  const authority = provider.wallet.publicKey;
+  const lamports =
+    await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  // InitializeMint2 { decimals, mint_authority, freeze_authority: None }
+  const data = Buffer.concat([
+    Buffer.from([20, decimals]),
+    authority.toBuffer(),
+    Buffer.from([0]),
+  ]);
+  await send(
+    SystemProgram.createAccount({
+      fromPubkey: authority,
+      newAccountPubkey: mint.publicKey,
+      lamports,
+      space: MINT_SIZE,
+      programId: TOKEN_PROGRAM_ID,
+    }),
+    new TransactionInstruction({
+      programId: TOKEN_PROGRAM_ID,
+      keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+      data,
+    })
+  )([mint]);
+  return mint.publicKey;
+}
+
+/** Creates `owner`'s associated token account (owner may be a PDA) */
+export async function createTokenAccount(
+  mint: PublicKey,
+  owner: PublicKey
+): Promise<PublicKey> {
+  const address = anchor.utils.token.associatedAddress({ mint, owner });
+  await send(
+    new TransactionInstruction({
+      programId: ASSOCIATED_TOKEN_PROGRAM_ID,
+      keys: [
+        { pubkey: provider.wallet.publicKey, isSigner: true, isWritable: true },
+        { pubkey: address, isSigner: false, isWritable: true },
+        { pubkey: owner, isSigner: false, isWritable: false },
+        { pubkey: mint, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      // CreateIdempotent
+      data: Buffer.from([1]),
+    })
+  )();
+  return address;
+}
+
+/** Mints `amount` base units into `destination` */
+export async function mintTo(
+  mint: PublicKey,
+  destination: PublicKey,
+  amount: number | BN
+): Promise<void> {
+  const data = Buffer.concat([
+    Buffer.from([7]),
+    new BN(amount).toArrayLike(Buffer, "le", 8),
+  ]);
+  await send(
+    new TransactionInstruction({
+      programId: TOKEN_PROGRAM_ID,
+      keys: [
+        { pubkey: mint, isSigner: false, isWritable: true },
+        { pubkey: destination, isSigner: false, isWritable: true },
+        { pubkey: provider.wallet.publicKey, isSigner: true, isWritable: false },
+      ],
+      data,
+    })
+  )();
+}
+
+export async function tokenBalance(account: PublicKey): Promise<number> {
+  const { value } = await provider.connection.getTokenAccountBalance(
+    account,
+    "confirmed"
+  );
+  return Number(value.amount);
+}
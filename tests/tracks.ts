@@ -0,0 +1,123 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  balance,
+  createContest,
+  distributePrizes,
+  errorCode,
+  fundContest,
+  fundedKeypair,
+  judgeVote,
+  program,
+  provider,
+  submissionPda,
+  submitEntry,
+  TestContest,
+  votePda,
+  waitUntil,
+} from "./helpers";
+
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+describe("tracks", () => {
+  const defi = new BN(LAMPORTS_PER_SOL / 10);
+  const gaming = new BN(LAMPORTS_PER_SOL / 5);
+
+  const configureTracks = (t: TestContest) =>
+    program.methods
+      .configureTracks([
+        { name: "DeFi", prizeAmount: defi },
+        { name: "Gaming", prizeAmount: gaming },
+      ])
+      .accountsPartial({ contest: t.contest, authority: t.creator.publicKey })
+      .signers([t.creator])
+      .rpc();
+
+  it("escrows the sum of the track prizes", async () => {
+    const t = await createContest();
+    await configureTracks(t);
+
+    const contest = await program.account.contest.fetch(t.contest);
+    expect(contest.prizeAmount.toString()).to.equal(defi.add(gaming).toString());
+    expect(contest.tracks.map((track) => track.name)).to.deep.equal([
+      "DeFi",
+      "Gaming",
+    ]);
+
+    const before = await balance(t.escrow);
+    await fundContest(t);
+    expect((await balance(t.escrow)) - before).to.equal(
+      defi.add(gaming).toNumber()
+    );
+  });
+
+  it("does not let a vote in one track pay out another", async () => {
+    const judge = await fundedKeypair();
+    const t = await createContest({
+      deadlineSecs: 10,
+      judges: [provider.wallet.publicKey, judge.publicKey],
+    });
+    await configureTracks(t);
+    await fundContest(t);
+
+    const alice = await fundedKeypair();
+    const bob = await fundedKeypair();
+    await submitEntry(t.contest, alice, "https://example.com/alice", {
+      track: 0,
+    }).rpc();
+    await submitEntry(t.contest, bob, "https://example.com/bob", {
+      track: 1,
+    }).rpc();
+
+    await waitUntil(t.deadline);
+    await judgeVote(t, alice.publicKey, 0);
+    // The second judge backs Bob, but in the DeFi track
+    await program.methods
+      .judgeVote(bob.publicKey, 0)
+      .accountsPartial({
+        contest: t.contest,
+        vote: votePda(t.contest, judge.publicKey, 0),
+        judge: judge.publicKey,
+      })
+      .signers([judge])
+      .rpc();
+
+    // Alice entered DeFi, so she cannot be paid the Gaming prize
+    expect(await errorCode(distributePrizes(t, alice.publicKey, 1))).to.equal(
+      "WrongTrack"
+    );
+    // A DeFi vote for Bob does not count towards the Gaming prize
+    const code = await errorCode(
+      program.methods
+        .distributePrizes(1)
+        .accountsPartial({
+          contest: t.contest,
+          escrow: t.escrow,
+          winner: bob.publicKey,
+          winnerSubmission: submissionPda(t.contest, bob.publicKey),
+        })
+        .remainingAccounts([
+          {
+            pubkey: votePda(t.contest, judge.publicKey, 0),
+            isSigner: false,
+            isWritable: false,
+          },
+        ])
+        .rpc()
+    );
+    expect(code).to.equal("ConsensusNotReached");
+
+    const before = await balance(alice.publicKey);
+    await distributePrizes(t, alice.publicKey, 0);
+    expect((await balance(alice.publicKey)) - before).to.equal(
+      defi.toNumber()
+    );
+    const contest = await program.account.contest.fetch(t.contest);
+    expect(contest.tracks[0].winner.toBase58()).to.equal(
+      alice.publicKey.toBase58()
+    );
+    expect(contest.tracks[1].winner).to.equal(null);
+    expect(contest.status).to.deep.equal({ active: {} });
+  });
+});
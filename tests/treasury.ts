@@ -0,0 +1,73 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  balance,
+  createContest,
+  distributePrizes,
+  ensureProtocol,
+  errorCode,
+  fundContest,
+  fundedKeypair,
+  judgeVote,
+  pda,
+  program,
+  provider,
+  submitEntry,
+  waitUntil,
+  withConfig,
+} from "./helpers";
+
+describe("platform fee treasury", () => {
+  const treasury = pda(Buffer.from("treasury"));
+
+  const withdraw = (
+    amount: number,
+    recipient: anchor.web3.PublicKey,
+    admin: anchor.web3.Keypair | null = null
+  ) => {
+    const call = program.methods
+      .withdrawTreasury(new anchor.BN(amount))
+      .accountsPartial({
+        treasury,
+        recipient,
+        admin: admin?.publicKey ?? provider.wallet.publicKey,
+      });
+    return (admin ? call.signers([admin]) : call).rpc();
+  };
+
+  before(ensureProtocol);
+
+  it("sends the snapshotted fee to the treasury and lets only the admin withdraw it", async () => {
+    // Contests keep the rate they were created with
+    const t = await withConfig({ platformFeeBps: 500 }, () =>
+      createContest({ deadlineSecs: 8 })
+    );
+    const contest = await program.account.contest.fetch(t.contest);
+    expect(contest.platformFeeBps).to.equal(500);
+    await fundContest(t);
+
+    const alice = await fundedKeypair();
+    await submitEntry(t.contest, alice, "https://example.com/alice").rpc();
+    await waitUntil(t.deadline);
+    await judgeVote(t, alice.publicKey);
+
+    const prize = contest.prizeAmount.toNumber();
+    const fee = (prize * 500) / 10_000;
+    const aliceBefore = await balance(alice.publicKey);
+    const treasuryBefore = await balance(treasury);
+    await distributePrizes(t, alice.publicKey);
+    expect((await balance(alice.publicKey)) - aliceBefore).to.equal(prize - fee);
+    expect((await balance(treasury)) - treasuryBefore).to.equal(fee);
+
+    const mallory = await fundedKeypair();
+    expect(await errorCode(withdraw(fee, mallory.publicKey, mallory))).to.equal(
+      "UnauthorizedAdmin"
+    );
+
+    // The treasury keeps its rent-exempt minimum, so withdraw part of the fee
+    const recipient = anchor.web3.Keypair.generate().publicKey;
+    await withdraw(fee / 2, recipient);
+    expect(await balance(recipient)).to.equal(fee / 2);
+    expect(await balance(treasury)).to.equal(treasuryBefore + fee / 2);
+  });
+});
@@ -0,0 +1,133 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  createContest,
+  errorCode,
+  fundContest,
+  fundedKeypair,
+  pda,
+  program,
+  submitEntry,
+} from "./helpers";
+
+const UPDATE_CONTEST = 1 << 4;
+
+describe("update_contest", () => {
+  const update = (
+    t: Awaited<ReturnType<typeof createContest>>,
+    deadline: BN | null,
+    description: string | null = null,
+    title: string | null = null
+  ) =>
+    program.methods
+      .updateContest(title, description, null, deadline, null, null, null, null)
+      .accountsPartial({
+        contest: t.contest,
+        operator: t.creator.publicKey,
+        role: null,
+      })
+      .signers([t.creator]);
+
+  it("moves the deadline freely before funding", async () => {
+    const t = await createContest();
+    const deadline = new BN(Math.floor(Date.now() / 1000) + 90 * 86400);
+    await update(t, deadline).rpc();
+
+    const contest = await program.account.contest.fetch(t.contest);
+    expect(contest.submissionDeadline.toString()).to.equal(deadline.toString());
+    expect(contest.deadlineExtension.toNumber()).to.equal(0);
+    expect(contest.revision).to.equal(1);
+  });
+
+  it("rejects deadline edits once funded, even with no participants", async () => {
+    const t = await createContest();
+    await fundContest(t);
+    const deadline = new BN(Math.floor(Date.now() / 1000) + 90 * 86400);
+
+    expect(await errorCode(update(t, deadline).rpc())).to.equal(
+      "DeadlineLocked"
+    );
+    // Non-material edits still go through
+    await update(t, null, "Updated description").rpc();
+  });
+
+  it("locks material fields once someone has entered", async () => {
+    const t = await createContest();
+    await fundContest(t);
+    const alice = await fundedKeypair();
+    await submitEntry(t.contest, alice, "https://example.com/alice").rpc();
+
+    expect(await errorCode(update(t, null, null, "New title").rpc())).to.equal(
+      "MaterialFieldLocked"
+    );
+    // Non-material edits still go through
+    await update(t, null, "Updated description").rpc();
+    const contest = await program.account.contest.fetch(t.contest);
+    expect(contest.description).to.equal("Updated description");
+  });
+
+  it("keeps judges out of an UPDATE_CONTEST delegate's reach", async () => {
+    const t = await createContest();
+    const delegate = await fundedKeypair();
+    const role = pda(
+      Buffer.from("role"),
+      t.contest.toBuffer(),
+      delegate.publicKey.toBuffer()
+    );
+    await program.methods
+      .grantRole(delegate.publicKey, UPDATE_CONTEST)
+      .accountsPartial({
+        contest: t.contest,
+        role,
+        authority: t.creator.publicKey,
+      })
+      .signers([t.creator])
+      .rpc();
+
+    const asDelegate = (
+      judges: anchor.web3.PublicKey[] | null,
+      description: string | null
+    ) =>
+      program.methods
+        .updateContest(null, description, null, null, judges, null, null, null)
+        .accountsPartial({
+          contest: t.contest,
+          operator: delegate.publicKey,
+          role,
+        })
+        .signers([delegate])
+        .rpc();
+
+    expect(await errorCode(asDelegate([delegate.publicKey], null))).to.equal(
+      "UnauthorizedCreator"
+    );
+    await asDelegate(null, "Edited by a delegate");
+
+    const contest = await program.account.contest.fetch(t.contest);
+    expect(contest.judges.map((j) => j.toBase58())).to.not.include(
+      delegate.publicKey.toBase58()
+    );
+    expect(contest.description).to.equal("Edited by a delegate");
+  });
+
+  it("rejects a zero approval threshold on create and update", async () => {
+    expect(await errorCode(createContest({ threshold: 0 }))).to.equal(
+      "InvalidThreshold"
+    );
+
+    const t = await createContest();
+    const code = await errorCode(
+      program.methods
+        .updateContest(null, null, null, null, null, 0, null, null)
+        .accountsPartial({
+          contest: t.contest,
+          operator: t.creator.publicKey,
+          role: null,
+        })
+        .signers([t.creator])
+        .rpc()
+    );
+    expect(code).to.equal("InvalidThreshold");
+  });
+});